@@ -0,0 +1,48 @@
+//! Compares the blocked matrix product with the textbook triple loop
+//!
+//! Run with `cargo bench --bench gemm`.
+extern crate ktensor as k;
+use std::time::{Duration, Instant};
+use k::math::{Matrix, Vec2};
+
+/// Textbook triple loop product over row-major buffers
+fn naive_product(a: &[f64], b: &[f64], n: usize) -> Vec<f64> {
+    let mut c = vec![0.0; n * n];
+    for i in 0..n {
+        for j in 0..n {
+            let mut sum = 0.0;
+            for l in 0..n {
+                sum += a[i * n + l] * b[l * n + j];
+            }
+            c[i * n + j] = sum;
+        }
+    }
+    c
+}
+
+/// Returns the fastest of `runs` timings of `f`
+fn time<F, U>(runs: usize, mut f: F) -> Duration where F: FnMut() -> U {
+    (0..runs).map(|_| {
+        let start = Instant::now();
+        let result = f();
+        let elapsed = start.elapsed();
+        drop(result);
+        elapsed
+    }).min().unwrap()
+}
+
+fn main() {
+    k::math::set_threads(1);
+    println!("{:>6} {:>12} {:>12} {:>8}", "n", "naive (ms)", "blocked (ms)", "speedup");
+    for &n in [64, 128, 256, 512, 1024].iter() {
+        let a: Vec<f64> = (0..n * n).map(|i| (i % 17) as f64 - 8.0).collect();
+        let b: Vec<f64> = (0..n * n).map(|i| (i % 13) as f64 - 6.0).collect();
+        let matrix_a = Matrix::new(Vec2(n, n), a.clone());
+        let matrix_b = Matrix::new(Vec2(n, n), b.clone());
+        let runs = if n <= 256 { 10 } else { 3 };
+        let naive = time(runs, || naive_product(&a, &b, n));
+        let blocked = time(runs, || &matrix_a * &matrix_b);
+        let ms = |d: Duration| d.as_secs() as f64 * 1e3 + d.subsec_nanos() as f64 / 1e6;
+        println!("{:>6} {:>12.3} {:>12.3} {:>7.2}x", n, ms(naive), ms(blocked), ms(naive) / ms(blocked));
+    }
+}
@@ -0,0 +1,47 @@
+//! Measures the cost of handing work to the worker pool, which sets `MIN_WORK` in `math::parallel`
+//!
+//! Run with `cargo bench --bench parallel`.
+extern crate ktensor as k;
+use std::thread;
+use std::time::{Duration, Instant};
+use k::math::{Matrix, Vec2};
+
+/// Returns the median of `runs` timings of `f` in microseconds
+fn time<F, U>(runs: usize, mut f: F) -> f64 where F: FnMut() -> U {
+    let mut timings: Vec<Duration> = (0..runs).map(|_| {
+        let start = Instant::now();
+        let result = f();
+        let elapsed = start.elapsed();
+        drop(result);
+        elapsed
+    }).collect();
+    timings.sort();
+    let median = timings[runs / 2];
+    median.as_secs() as f64 * 1e6 + median.subsec_nanos() as f64 / 1e3
+}
+
+fn main() {
+    let runs = 200;
+
+    println!("elementwise addition on one thread");
+    println!("{:>10} {:>10} {:>12}", "items", "time (µs)", "items / µs");
+    k::math::set_threads(1);
+    for shift in 12..21 {
+        let len = 1 << shift;
+        let a = Matrix::new(Vec2(len / 64, 64), vec![1.0f64; len]);
+        let b = Matrix::new(Vec2(len / 64, 64), vec![2.0f64; len]);
+        let elapsed = time(runs, || &a + &b);
+        println!("{:>10} {:>10.1} {:>12.0}", len, elapsed, len as f64 / elapsed);
+    }
+
+    println!("\noverhead of splitting one addition of 2^16 items in two");
+    let len = 1 << 16;
+    let a = Matrix::new(Vec2(len / 64, 64), vec![1.0f64; len]);
+    let b = Matrix::new(Vec2(len / 64, 64), vec![2.0f64; len]);
+    let serial = time(runs, || &a + &b);
+    k::math::set_threads(2);
+    let pooled = time(runs, || &a + &b);
+    let spawned = time(runs, || thread::scope(|scope| scope.spawn(|| ()).join().unwrap()));
+    println!("1 thread: {:.1}µs | 2 pooled threads: {:.1}µs | spawning and joining a scoped thread: {:.1}µs", serial, pooled, spawned);
+    println!("available cores: {}", thread::available_parallelism().map(|n| n.get()).unwrap_or(1));
+}
@@ -1,49 +1,153 @@
 use std::string::{String};
-use std::collections::{HashMap};
+use std::collections::{HashMap, BTreeMap};
+use std::collections::hash_map::{Iter, IterMut};
+use std::fmt;
 use tensor::{Tensor};
 use node::{Graph};
-use math::{Vec2};
+use math::{Vec2, SparseMatrix};
+use error::{KTensorError};
 
 /// Context Map
 pub struct Context<T> {
     /// map of nodeids and values
     map: HashMap<String, Tensor<T>>,
+    /// map of nodeids and sparse values
+    sparse: HashMap<String, SparseMatrix<T>>,
 }
 
 impl <T> Context<T> where T: Copy {
     pub fn new() -> Context<T> {
         Context {
             map: HashMap::new(),
+            sparse: HashMap::new(),
         }
     }
 
     pub fn with_capacity(size: usize) -> Context<T> {
         Context {
             map: HashMap::with_capacity(size),
+            sparse: HashMap::new(),
         }
     }
 
-    pub fn from_vec(context_vec: Vec<(&Graph<T>, Tensor<T>)>) -> Context<T> {
+    pub fn from_vec(context_vec: Vec<(&dyn Graph<T>, Tensor<T>)>) -> Context<T> {
+        match Context::try_from_vec(context_vec) {
+            Ok(context) => context,
+            Err(error)  => panic!("{}", error),
+        }
+    }
+
+    /// Returns a `Context` of batches keyed by the id of their node,
+    /// or a `ShapeMismatch` if a batch does not have the dimensions of its node
+    pub fn try_from_vec(context_vec: Vec<(&dyn Graph<T>, Tensor<T>)>) -> Result<Context<T>, KTensorError> {
         let mut context_map = HashMap::with_capacity(context_vec.len());
 
         for (node, batch) in context_vec {
             let Vec2(x1, y1) = node.get_dim();
             let Vec2(x2, y2) = batch.dim();
-            assert_eq!(x1, x2);
-            assert_eq!(y1, y2);
+            if x1 != x2 || y1 != y2 {
+                return Err(KTensorError::shape_mismatch(node.get_dim(), batch.dim()));
+            }
             context_map.insert(node.get_id(), batch);
         }
 
-        Context {
-            map: context_map
-        }
+        Ok(Context {
+            map: context_map,
+            sparse: HashMap::new(),
+        })
     }
 
     pub fn get(&self, nodeid: String) -> Option<&Tensor<T>> {
         self.map.get(&nodeid)
     }
 
+    pub fn get_mut(&mut self, nodeid: String) -> Option<&mut Tensor<T>> {
+        self.map.get_mut(&nodeid)
+    }
+
+    /// Returns the `Tensor` of `nodeid`, or a `MissingKey` if none is set
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let context: ktensor::Context<f64> = ktensor::Context::new();
+    /// assert_eq!(context.try_get("x".to_string()).err(), Some(ktensor::KTensorError::MissingKey("x".to_string())));
+    /// ```
+    pub fn try_get(&self, nodeid: String) -> Result<&Tensor<T>, KTensorError> {
+        match self.map.get(&nodeid) {
+            Some(tensor) => Ok(tensor),
+            None         => Err(KTensorError::MissingKey(nodeid)),
+        }
+    }
+
+    /// Removes every `Tensor` and `SparseMatrix` from the `Context`
+    pub fn clear(&mut self) {
+        self.map.clear();
+        self.sparse.clear();
+    }
+
     pub fn set(&mut self, nodeid: String, tensor: Tensor<T>) {
         self.map.insert(nodeid, tensor);
     }
+
+    pub fn get_sparse(&self, nodeid: String) -> Option<&SparseMatrix<T>> {
+        self.sparse.get(&nodeid)
+    }
+
+    /// Sets the sparse value of `nodeid`, read by nodes such as `op::sparse_dot` instead of a `Tensor`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut context = ktensor::Context::new();
+    /// context.set_sparse("x".to_string(), ktensor::math::SparseMatrix::from_triplets(ktensor::math::Vec2(2, 2), vec![(1, 0, 3.0)]));
+    /// assert_eq!(context.get_sparse("x".to_string()).unwrap().nnz(), 1);
+    /// assert!(context.get("x".to_string()).is_none());
+    /// ```
+    pub fn set_sparse(&mut self, nodeid: String, matrix: SparseMatrix<T>) {
+        self.sparse.insert(nodeid, matrix);
+    }
+
+    /// Iterates over the node ids and tensors of the `Context` in arbitrary order
+    pub fn iter(&self) -> Iter<'_, String, Tensor<T>> {
+        self.map.iter()
+    }
+
+    /// Iterates over the node ids and mutable tensors of the `Context` in arbitrary order
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut gradients = ktensor::Context::new();
+    /// gradients.set("w".to_string(), ktensor::Tensor::from_vec(ktensor::math::Vec2(1, 2), vec![-3.0, 0.5]));
+    /// for (_, gradient) in gradients.iter_mut() {
+    ///     *gradient = gradient.map(|x: f64| x.max(-1.0).min(1.0));
+    /// }
+    /// assert_eq!(gradients.get("w".to_string()).unwrap().clone().to_flattened(), vec![-1.0, 0.5]);
+    /// ```
+    pub fn iter_mut(&mut self) -> IterMut<'_, String, Tensor<T>> {
+        self.map.iter_mut()
+    }
+}
+
+impl <T> Default for Context<T> where T: Copy {
+    fn default() -> Context<T> {
+        Context::new()
+    }
+}
+
+impl <T> fmt::Debug for Context<T> where T: Copy + fmt::Debug {
+    /// Formats the tensors of the `Context` ordered by node id
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut context = ktensor::Context::new();
+    /// context.set("b".to_string(), ktensor::Tensor::from_vec(ktensor::math::Vec2(1, 1), vec![2]));
+    /// context.set("a".to_string(), ktensor::Tensor::from_vec(ktensor::math::Vec2(1, 1), vec![1]));
+    /// assert_eq!(format!("{:?}", context), "{\"a\": Tensor([[1]], shape=[1, 1]), \"b\": Tensor([[2]], shape=[1, 1])}");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map().entries(self.map.iter().collect::<BTreeMap<_, _>>()).finish()
+    }
 }
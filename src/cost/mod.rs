@@ -1,3 +1,3 @@
 mod softxentropy;
 
-pub use self::softxentropy::{softmax_cross_entropy_f64, softmax_cross_entropy_f32};
+pub use self::softxentropy::{softmax_cross_entropy};
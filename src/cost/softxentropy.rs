@@ -1,28 +1,18 @@
 use std::string::{String};
 use std::sync::{Arc};
-use math::{Vec2};
+use math::{Vec2, Float};
 use node::{Node, Graph};
 use tensor::{Tensor};
 
-fn operation_f64(vec: Vec<Tensor<f64>>) -> Tensor<f64> {
+fn operation<T>(vec: Vec<Tensor<T>>) -> Tensor<T> where T: Float {
     // y ln a + (1 - y) ln (1 - a)
-    Tensor::from_vec(Vec2(1, 1), vec![vec[0].buffer().iter().zip(vec[1].buffer().iter()).fold(0.0, |sum, (a, y)| {
-        sum - (y * a.ln() + (1.0 - y) * (1.0 - a).ln())
-    }) / vec[0].buffer().len() as f64])
+    Tensor::from_vec(Vec2(1, 1), vec![vec[0].iter().zip(vec[1].iter()).fold(T::zero(), |sum, (a, y)| {
+        sum - (y * a.ln() + (T::one() - y) * (T::one() - a).ln())
+    }) / T::from_usize(vec[0].shape().len())])
 }
 
-fn operation_f32(vec: Vec<Tensor<f32>>) -> Tensor<f32> {
-    Tensor::from_vec(Vec2(1, 1), vec![vec[0].buffer().iter().zip(vec[1].buffer().iter()).fold(0.0, |sum, (a, y)| {
-        sum - (y * a.ln() + (1.0 - y) * (1.0 - a).ln())
-    }) / vec[0].buffer().len() as f32])
-}
-
-fn operation_prime_f64(_: &Tensor<f64>, vec: Vec<&Tensor<f64>>) -> Vec<Tensor<f64>> {
-    vec![vec[0] + &(vec[1] * &-1.0)]
-}
-
-fn operation_prime_f32(_: &Tensor<f32>, vec: Vec<&Tensor<f32>>) -> Vec<Tensor<f32>> {
-    vec![vec[0] + &(vec[1] * &-1.0)]
+fn operation_prime<T>(_: &Tensor<T>, vec: Vec<&Tensor<T>>) -> Vec<Tensor<T>> where T: Float {
+    vec![vec[0] - vec[1]]
 }
 
 fn calc_dim(dims: Vec<Vec2>) -> Vec2 {
@@ -33,10 +23,6 @@ fn calc_dim(dims: Vec<Vec2>) -> Vec2 {
     Vec2(1, 1)
 }
 
-pub fn softmax_cross_entropy_f64(node_id: String, s: Arc<Graph<f64>>, y: Arc<Graph<f64>>) -> Node<f64> {
-    Node::new(node_id, operation_f64, operation_prime_f64, vec![s, y], calc_dim)
-}
-
-pub fn softmax_cross_entropy_f32(node_id: String, s: Arc<Graph<f32>>, y: Arc<Graph<f32>>) -> Node<f32> {
-    Node::new(node_id, operation_f32, operation_prime_f32, vec![s, y], calc_dim)
+pub fn softmax_cross_entropy<T>(node_id: String, s: Arc<dyn Graph<T>>, y: Arc<dyn Graph<T>>) -> Node<T> where T: Float {
+    Node::new(node_id, operation, operation_prime, vec![s, y], calc_dim)
 }
@@ -0,0 +1,51 @@
+use std::error;
+use std::fmt;
+use std::string::{String};
+use math::{Vec2};
+
+/// Reason a fallible `ktensor` call could not produce a result
+#[derive(Clone, Debug, PartialEq)]
+pub enum KTensorError {
+    /// dimensions of two operands, or of an operand and its values, do not fit together
+    ShapeMismatch {
+        /// dimensions of the left operand or the expected dimensions
+        lhs: Vec<usize>,
+        /// dimensions of the right operand or the dimensions found
+        rhs: Vec<usize>,
+    },
+    /// no `Tensor` is set for the node id in a `Context`
+    MissingKey(String),
+    /// an argument is outside the values accepted by the call
+    InvalidArgument(String),
+    /// a linear algebra routine needs a square `Matrix`, but got `(rows, columns)`
+    NotSquare(usize, usize),
+    /// the `Matrix` has no inverse
+    Singular,
+    /// an iterative linear algebra routine did not converge within its sweep limit
+    NoConvergence,
+}
+
+impl KTensorError {
+    /// Returns a `ShapeMismatch` between two matrix dimensions
+    pub fn shape_mismatch(lhs: Vec2, rhs: Vec2) -> KTensorError {
+        KTensorError::ShapeMismatch {
+            lhs: vec![lhs.0, lhs.1],
+            rhs: vec![rhs.0, rhs.1],
+        }
+    }
+}
+
+impl fmt::Display for KTensorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            KTensorError::ShapeMismatch { ref lhs, ref rhs } => write!(f, "shape mismatch between {:?} and {:?}", lhs, rhs),
+            KTensorError::MissingKey(ref key)                => write!(f, "no tensor is set for node {}", key),
+            KTensorError::InvalidArgument(ref message)       => write!(f, "invalid argument: {}", message),
+            KTensorError::NotSquare(row, col)                => write!(f, "expected a square matrix, got ({}, {})", row, col),
+            KTensorError::Singular                           => write!(f, "matrix is singular"),
+            KTensorError::NoConvergence                      => write!(f, "iteration did not converge"),
+        }
+    }
+}
+
+impl error::Error for KTensorError {}
@@ -0,0 +1,20 @@
+use math::{Vec2};
+use init::{Initializer};
+
+/// Starts every value at 0, the usual choice for biases
+pub struct Zeros;
+
+impl Initializer for Zeros {
+    fn sample(&self, Vec2(row, col): Vec2) -> Vec<f64> {
+        vec![0.0; row * col]
+    }
+}
+
+/// Starts every value at the same constant
+pub struct Constant(pub f64);
+
+impl Initializer for Constant {
+    fn sample(&self, Vec2(row, col): Vec2) -> Vec<f64> {
+        vec![self.0; row * col]
+    }
+}
@@ -0,0 +1,50 @@
+use math::{Vec2};
+use random;
+use init::{Initializer};
+
+/// Draws values uniformly from `[low, high)`
+///
+/// # Example
+///
+/// ```
+/// use ktensor::init::Initializer;
+/// let values = ktensor::init::Uniform { low: -0.1, high: 0.1 }.sample(ktensor::math::Vec2(8, 8));
+/// assert!(values.iter().all(|&x| x >= -0.1 && x < 0.1));
+/// ```
+pub struct Uniform {
+    pub low: f64,
+    pub high: f64,
+}
+
+impl Initializer for Uniform {
+    fn sample(&self, Vec2(row, col): Vec2) -> Vec<f64> {
+        random::uniform(row * col, self.low, self.high)
+    }
+}
+
+/// Draws values from a normal distribution, redrawing any more than 2 standard deviations from the mean
+///
+/// # Example
+///
+/// ```
+/// use ktensor::init::Initializer;
+/// let values = ktensor::init::TruncatedNormal { mean: 1.0, std_dev: 0.5 }.sample(ktensor::math::Vec2(32, 32));
+/// assert_eq!(values.len(), 1024);
+/// assert!(values.iter().all(|&x| x >= 0.0 && x <= 2.0));
+/// ```
+pub struct TruncatedNormal {
+    pub mean: f64,
+    pub std_dev: f64,
+}
+
+impl Initializer for TruncatedNormal {
+    fn sample(&self, Vec2(row, col): Vec2) -> Vec<f64> {
+        let bound = 2.0 * self.std_dev;
+        let mut values = Vec::with_capacity(row * col);
+        while values.len() < row * col {
+            let needed = row * col - values.len();
+            values.extend(random::normal(needed, self.mean, self.std_dev).into_iter().filter(|x| (x - self.mean).abs() <= bound));
+        }
+        values
+    }
+}
@@ -0,0 +1,34 @@
+use math::{Vec2};
+
+mod constant;
+mod distribution;
+mod variance;
+mod orthogonal;
+
+pub use self::constant::{Zeros, Constant};
+pub use self::distribution::{Uniform, TruncatedNormal};
+pub use self::variance::{GlorotNormal, GlorotUniform, HeNormal, HeUniform, LecunNormal, LecunUniform};
+pub use self::orthogonal::{Orthogonal};
+
+/// Strategy for the starting values of a `State`
+///
+/// A `State` of dimensions `(rows, columns)` multiplies inputs from the right,
+/// so its fan in is `rows` and its fan out is `columns`.
+///
+/// # Example
+///
+/// ```
+/// use ktensor::init::Initializer;
+/// let values = ktensor::init::GlorotUniform.sample(ktensor::math::Vec2(4, 8));
+/// let limit = (6.0f64 / 12.0).sqrt();
+/// assert!(values.iter().all(|&x| x.abs() <= limit));
+/// ```
+pub trait Initializer: Send + Sync {
+    /// Returns the row-major values of a `State` of dimensions `dim`
+    fn sample(&self, dim: Vec2) -> Vec<f64>;
+}
+
+/// Returns the fan in and fan out of a `State` of dimensions `dim`, at least 1 each
+fn fans(Vec2(row, col): Vec2) -> (f64, f64) {
+    (row.max(1) as f64, col.max(1) as f64)
+}
@@ -0,0 +1,38 @@
+use math::{Matrix, Vec2};
+use random;
+use init::{Initializer};
+
+/// Starts with orthonormal rows or columns, whichever are fewer, scaled by `gain`
+///
+/// The orthonormal factor of a gaussian matrix is used, with signs fixed by the diagonal of `R` so it is uniformly distributed.
+///
+/// # Example
+///
+/// ```
+/// use ktensor::init::Initializer;
+/// let values = ktensor::init::Orthogonal { gain: 1.0 }.sample(ktensor::math::Vec2(3, 5));
+/// let w = ktensor::math::Matrix::new(ktensor::math::Vec2(3, 5), values);
+/// for (x, y) in (&w * &w.transpose()).iter().zip(vec![1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0]) {
+///     assert!((x - y).abs() < 1e-12);
+/// }
+/// ```
+pub struct Orthogonal {
+    pub gain: f64,
+}
+
+impl Initializer for Orthogonal {
+    fn sample(&self, Vec2(row, col): Vec2) -> Vec<f64> {
+        let (long, short) = if row >= col { (row, col) } else { (col, row) };
+        if short == 0 {
+            return Vec::new();
+        }
+        let qr = Matrix::new(Vec2(long, short), random::normal(long * short, 0.0, 1.0)).qr();
+        let signs: Vec<f64> = (0..short).map(|j| if qr.r().get(Vec2(j, j)) < 0.0 { -self.gain } else { self.gain }).collect();
+        let q = qr.q().product(&Matrix::new(Vec2(1, short), signs));
+        if row >= col {
+            q.to_flattened()
+        } else {
+            q.transpose().to_flattened()
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use math::{Vec2};
+use random;
+use init::{Initializer, fans};
+
+/// Glorot (Xavier) normal, standard deviation `sqrt(2 / (fan_in + fan_out))`
+pub struct GlorotNormal;
+
+impl Initializer for GlorotNormal {
+    fn sample(&self, dim: Vec2) -> Vec<f64> {
+        let (fan_in, fan_out) = fans(dim);
+        random::normal(dim.0 * dim.1, 0.0, (2.0 / (fan_in + fan_out)).sqrt())
+    }
+}
+
+/// Glorot (Xavier) uniform, limit `sqrt(6 / (fan_in + fan_out))`
+pub struct GlorotUniform;
+
+impl Initializer for GlorotUniform {
+    fn sample(&self, dim: Vec2) -> Vec<f64> {
+        let (fan_in, fan_out) = fans(dim);
+        let limit = (6.0 / (fan_in + fan_out)).sqrt();
+        random::uniform(dim.0 * dim.1, -limit, limit)
+    }
+}
+
+/// He normal, standard deviation `sqrt(2 / fan_in)`, the default of a `State`
+pub struct HeNormal;
+
+impl Initializer for HeNormal {
+    fn sample(&self, dim: Vec2) -> Vec<f64> {
+        let (fan_in, _) = fans(dim);
+        random::normal(dim.0 * dim.1, 0.0, (2.0 / fan_in).sqrt())
+    }
+}
+
+/// He uniform, limit `sqrt(6 / fan_in)`
+pub struct HeUniform;
+
+impl Initializer for HeUniform {
+    fn sample(&self, dim: Vec2) -> Vec<f64> {
+        let (fan_in, _) = fans(dim);
+        let limit = (6.0 / fan_in).sqrt();
+        random::uniform(dim.0 * dim.1, -limit, limit)
+    }
+}
+
+/// LeCun normal, standard deviation `sqrt(1 / fan_in)`
+pub struct LecunNormal;
+
+impl Initializer for LecunNormal {
+    fn sample(&self, dim: Vec2) -> Vec<f64> {
+        let (fan_in, _) = fans(dim);
+        random::normal(dim.0 * dim.1, 0.0, (1.0 / fan_in).sqrt())
+    }
+}
+
+/// LeCun uniform, limit `sqrt(3 / fan_in)`
+pub struct LecunUniform;
+
+impl Initializer for LecunUniform {
+    fn sample(&self, dim: Vec2) -> Vec<f64> {
+        let (fan_in, _) = fans(dim);
+        let limit = (3.0 / fan_in).sqrt();
+        random::uniform(dim.0 * dim.1, -limit, limit)
+    }
+}
@@ -7,6 +7,22 @@
 //! - `context`
 //! - `node`
 //! - `op`
+//! - `cost`
+//! - `run`
+//! - `optimizer`
+//! - `init`
+//! - `random`
+//! - `error`
+//!
+//! # Traits
+//!
+//! - `Float`
+//! - `Graph`
+//! - `Optimizer`
+//!
+//! # Enums
+//!
+//! - `KTensorError`
 //!
 //! # Structs
 //!
@@ -22,11 +38,17 @@ pub mod node;
 pub mod op;
 pub mod cost;
 pub mod run;
+pub mod optimizer;
+pub mod init;
+pub mod random;
+pub mod error;
 
-pub use math::{Vec2};
+pub use math::{Vec2, Shape, Float};
 pub use tensor::{Tensor};
 pub use context::{Context};
-pub use node::{Graph, Node, State, Variable};
-pub use run::{execute, train};
+pub use node::{Graph, Node, State, Variable, SparseVariable};
+pub use run::{execute, train, train_with, gradients, apply_gradients};
+pub use optimizer::{Optimizer};
+pub use error::{KTensorError};
 
 pub use std::sync::{Arc};
@@ -0,0 +1,53 @@
+/// Conversion between element types of a `Tensor`
+///
+/// Numbers convert like `as`, so floats are truncated towards zero and saturate at the bounds of integers.
+/// `true` converts to 1 and numbers convert to `true` when they are not 0.
+pub trait Cast<U> {
+    /// Converts this value to `U`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ktensor::math::Cast;
+    /// assert_eq!(Cast::<i32>::cast(2.7f64), 2);
+    /// assert_eq!(Cast::<f32>::cast(true), 1.0);
+    /// ```
+    fn cast(self) -> U;
+}
+
+macro_rules! impl_cast {
+    ($from:ident => $($to:ident),*) => {
+        $(
+            impl Cast<$to> for $from {
+                fn cast(self) -> $to {
+                    self as $to
+                }
+            }
+        )*
+
+        impl Cast<bool> for $from {
+            fn cast(self) -> bool {
+                self != (0 as $from)
+            }
+        }
+
+        impl Cast<$from> for bool {
+            fn cast(self) -> $from {
+                (self as u8) as $from
+            }
+        }
+    };
+    ($($from:ident),*) => {
+        $(
+            impl_cast!($from => i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, f32, f64);
+        )*
+    };
+}
+
+impl_cast!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, f32, f64);
+
+impl Cast<bool> for bool {
+    fn cast(self) -> bool {
+        self
+    }
+}
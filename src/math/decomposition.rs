@@ -0,0 +1,304 @@
+use std::cmp;
+use math::{Matrix, Vec2, Float};
+use error::{KTensorError};
+
+/// maximum number of Jacobi sweeps before giving up
+const MAX_SWEEPS: usize = 64;
+
+/// QR decomposition, `A = QR`
+///
+/// For an `m x n` matrix with `k = min(m, n)`, `Q` is `m x k` with orthonormal columns and `R` is `k x n` upper triangular.
+pub struct Qr<T> {
+    /// orthonormal factor
+    q: Matrix<T>,
+    /// upper triangular factor
+    r: Matrix<T>,
+}
+
+impl <T> Qr<T> {
+    /// Returns the `Q` factor
+    pub fn q(&self) -> &Matrix<T> {
+        &self.q
+    }
+
+    /// Returns the `R` factor
+    pub fn r(&self) -> &Matrix<T> {
+        &self.r
+    }
+}
+
+/// Singular value decomposition, `A = U diag(S) V^T`
+///
+/// For an `m x n` matrix with `k = min(m, n)`, `U` is `m x k`, `S` has `k` values in descending order and `V` is `n x k`.
+/// Columns of `U` paired with a singular value of 0 are left as 0.
+pub struct Svd<T> {
+    /// left singular vectors
+    u: Matrix<T>,
+    /// singular values
+    s: Vec<T>,
+    /// right singular vectors
+    v: Matrix<T>,
+}
+
+impl <T> Svd<T> {
+    /// Returns the left singular vectors as columns
+    pub fn u(&self) -> &Matrix<T> {
+        &self.u
+    }
+
+    /// Returns the singular values in descending order
+    pub fn singular_values(&self) -> &[T] {
+        &self.s
+    }
+
+    /// Returns the right singular vectors as columns
+    pub fn v(&self) -> &Matrix<T> {
+        &self.v
+    }
+}
+
+/// Eigendecomposition of a symmetric matrix, `A = V diag(W) V^T`
+pub struct SymmetricEigen<T> {
+    /// eigenvalues
+    values: Vec<T>,
+    /// orthonormal eigenvectors
+    vectors: Matrix<T>,
+}
+
+impl <T> SymmetricEigen<T> {
+    /// Returns the eigenvalues in ascending order
+    pub fn eigenvalues(&self) -> &[T] {
+        &self.values
+    }
+
+    /// Returns the eigenvectors as columns, in the order of the eigenvalues
+    pub fn eigenvectors(&self) -> &Matrix<T> {
+        &self.vectors
+    }
+}
+
+/// Rotates columns `p` and `q` of the row-major buffer `a` with `cols` columns by `(c, s)`
+fn rotate_cols<T>(a: &mut [T], cols: usize, p: usize, q: usize, c: T, s: T) where T: Float {
+    for row in a.chunks_mut(cols) {
+        let (a_p, a_q) = (row[p], row[q]);
+        row[p] = c * a_p - s * a_q;
+        row[q] = s * a_p + c * a_q;
+    }
+}
+
+/// Returns the row-major buffer of the columns `order` of `a`
+fn select_cols<T>(a: &[T], cols: usize, order: &[usize]) -> Vec<T> where T: Copy {
+    a.chunks(cols).flat_map(|row| order.iter().map(move |&j| row[j])).collect()
+}
+
+impl <T> Matrix<T> where T: Float {
+    /// Decomposes the `Matrix` into `QR` with Householder reflections
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let a = ktensor::math::Matrix::new(ktensor::math::Vec2(3, 2), vec![12.0f64, -51.0, 6.0, 167.0, -4.0, 24.0]);
+    /// let qr = a.qr();
+    /// assert!(qr.r().get(ktensor::math::Vec2(1, 0)) == 0.0);
+    /// for (x, y) in (qr.q() * qr.r()).iter().zip(a.iter()) {
+    ///     assert!((x - y).abs() < 1e-9);
+    /// }
+    /// for (x, y) in (&qr.q().transpose() * qr.q()).iter().zip(vec![1.0, 0.0, 0.0, 1.0]) {
+    ///     assert!((x - y).abs() < 1e-12);
+    /// }
+    /// ```
+    pub fn qr(&self) -> Qr<T> {
+        let Vec2(m, n) = self.dim();
+        let k = cmp::min(m, n);
+        let mut a: Vec<T> = self.iter().collect();
+        let mut reflectors = Vec::with_capacity(k);
+        let two = T::one() + T::one();
+
+        for j in 0..k {
+            let norm = (j..m).fold(T::zero(), |sum, i| sum + a[i * n + j] * a[i * n + j]).sqrt();
+            let alpha = if a[j * n + j] < T::zero() { norm } else { -norm };
+            let mut v: Vec<T> = (j..m).map(|i| a[i * n + j]).collect();
+            v[0] = v[0] - alpha;
+            let v_norm = v.iter().fold(T::zero(), |sum, &x| sum + x * x).sqrt();
+            if v_norm > T::zero() {
+                for x in v.iter_mut() {
+                    *x = *x / v_norm;
+                }
+                for c in j..n {
+                    let dot = v.iter().enumerate().fold(T::zero(), |sum, (i, &x)| sum + x * a[(j + i) * n + c]);
+                    for (i, &x) in v.iter().enumerate() {
+                        a[(j + i) * n + c] = a[(j + i) * n + c] - two * x * dot;
+                    }
+                }
+            }
+            reflectors.push(v);
+        }
+
+        let mut q = vec![T::zero(); m * k];
+        for i in 0..k {
+            q[i * k + i] = T::one();
+        }
+        for (j, v) in reflectors.iter().enumerate().rev() {
+            for c in 0..k {
+                let dot = v.iter().enumerate().fold(T::zero(), |sum, (i, &x)| sum + x * q[(j + i) * k + c]);
+                for (i, &x) in v.iter().enumerate() {
+                    q[(j + i) * k + c] = q[(j + i) * k + c] - two * x * dot;
+                }
+            }
+        }
+
+        let r = (0..k * n).map(|l| if l / n <= l % n { a[l] } else { T::zero() }).collect();
+        Qr {
+            q: Matrix::new(Vec2(m, k), q),
+            r: Matrix::new(Vec2(k, n), r),
+        }
+    }
+
+    /// Decomposes the `Matrix` into `U diag(S) V^T` with one-sided Jacobi rotations
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let a = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), vec![3.0f64, 2.0, 2.0, 2.0, 3.0, -2.0]);
+    /// let svd = a.svd().unwrap();
+    /// assert!((svd.singular_values()[0] - 5.0).abs() < 1e-12);
+    /// assert!((svd.singular_values()[1] - 3.0).abs() < 1e-12);
+    /// let s = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 2), vec![svd.singular_values()[0], 0.0, 0.0, svd.singular_values()[1]]);
+    /// for (x, y) in (&(svd.u() * &s) * &svd.v().transpose()).iter().zip(a.iter()) {
+    ///     assert!((x - y).abs() < 1e-12);
+    /// }
+    /// ```
+    pub fn svd(&self) -> Result<Svd<T>, KTensorError> {
+        let Vec2(m, n) = self.dim();
+        if m < n {
+            let svd = self.transpose().svd()?;
+            return Ok(Svd {
+                u: svd.v,
+                s: svd.s,
+                v: svd.u,
+            });
+        }
+
+        let mut u: Vec<T> = self.iter().collect();
+        let mut v = vec![T::zero(); n * n];
+        for i in 0..n {
+            v[i * n + i] = T::one();
+        }
+
+        let mut converged = false;
+        for _ in 0..MAX_SWEEPS {
+            let mut rotated = false;
+            for p in 0..n {
+                for q in p + 1..n {
+                    let (alpha, beta, gamma) = u.chunks(n).fold((T::zero(), T::zero(), T::zero()), |(alpha, beta, gamma), row| {
+                        (alpha + row[p] * row[p], beta + row[q] * row[q], gamma + row[p] * row[q])
+                    });
+                    if gamma == T::zero() || gamma.abs() <= T::epsilon() * (alpha * beta).sqrt() {
+                        continue;
+                    }
+                    rotated = true;
+                    let zeta = (beta - alpha) / (gamma + gamma);
+                    let t = T::one() / (zeta.abs() + (T::one() + zeta * zeta).sqrt());
+                    let t = if zeta < T::zero() { -t } else { t };
+                    let c = (T::one() + t * t).sqrt().recip();
+                    let s = c * t;
+                    rotate_cols(&mut u, n, p, q, c, s);
+                    rotate_cols(&mut v, n, p, q, c, s);
+                }
+            }
+            if !rotated {
+                converged = true;
+                break;
+            }
+        }
+        if !converged {
+            return Err(KTensorError::NoConvergence);
+        }
+
+        let sigma: Vec<T> = (0..n).map(|j| u.chunks(n).fold(T::zero(), |sum, row| sum + row[j] * row[j]).sqrt()).collect();
+        for row in u.chunks_mut(n) {
+            for (x, &s) in row.iter_mut().zip(sigma.iter()) {
+                *x = if s > T::zero() { *x / s } else { T::zero() };
+            }
+        }
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&i, &j| sigma[j].partial_cmp(&sigma[i]).unwrap_or(cmp::Ordering::Equal));
+
+        Ok(Svd {
+            u: Matrix::new(Vec2(m, n), select_cols(&u, n, &order)),
+            s: order.iter().map(|&j| sigma[j]).collect(),
+            v: Matrix::new(Vec2(n, n), select_cols(&v, n, &order)),
+        })
+    }
+
+    /// Decomposes the symmetric part of a square `Matrix` into `V diag(W) V^T` with cyclic Jacobi rotations
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let a = ktensor::math::Matrix::new(ktensor::math::Vec2(3, 3), vec![2.0f64, -1.0, 0.0, -1.0, 2.0, -1.0, 0.0, -1.0, 2.0]);
+    /// let eigen = a.symmetric_eigen().unwrap();
+    /// let w = eigen.eigenvalues();
+    /// assert!((w[0] - (2.0 - 2f64.sqrt())).abs() < 1e-12);
+    /// assert!((w[2] - (2.0 + 2f64.sqrt())).abs() < 1e-12);
+    /// let v = eigen.eigenvectors();
+    /// let d = ktensor::math::Matrix::new(ktensor::math::Vec2(3, 3), vec![w[0], 0.0, 0.0, 0.0, w[1], 0.0, 0.0, 0.0, w[2]]);
+    /// for (x, y) in (&(v * &d) * &v.transpose()).iter().zip(a.iter()) {
+    ///     assert!((x - y).abs() < 1e-12);
+    /// }
+    /// ```
+    pub fn symmetric_eigen(&self) -> Result<SymmetricEigen<T>, KTensorError> {
+        let Vec2(row, col) = self.dim();
+        if row != col {
+            return Err(KTensorError::NotSquare(row, col));
+        }
+        let n = row;
+        let half = T::from_f64(0.5);
+        let mut a: Vec<T> = (0..n * n).map(|l| (self.get(Vec2(l / n, l % n)) + self.get(Vec2(l % n, l / n))) * half).collect();
+        let mut v = vec![T::zero(); n * n];
+        for i in 0..n {
+            v[i * n + i] = T::one();
+        }
+
+        let total = a.iter().fold(T::zero(), |sum, &x| sum + x * x);
+        let tolerance = T::epsilon() * T::epsilon() * total;
+        let mut converged = false;
+        for _ in 0..MAX_SWEEPS {
+            let off = (0..n * n).filter(|l| l / n != l % n).fold(T::zero(), |sum, l| sum + a[l] * a[l]);
+            if off <= tolerance {
+                converged = true;
+                break;
+            }
+            for p in 0..n {
+                for q in p + 1..n {
+                    let a_pq = a[p * n + q];
+                    if a_pq == T::zero() {
+                        continue;
+                    }
+                    let theta = (a[q * n + q] - a[p * n + p]) / (a_pq + a_pq);
+                    let t = T::one() / (theta.abs() + (theta * theta + T::one()).sqrt());
+                    let t = if theta < T::zero() { -t } else { t };
+                    let c = (t * t + T::one()).sqrt().recip();
+                    let s = t * c;
+                    rotate_cols(&mut a, n, p, q, c, s);
+                    for k in 0..n {
+                        let (a_pk, a_qk) = (a[p * n + k], a[q * n + k]);
+                        a[p * n + k] = c * a_pk - s * a_qk;
+                        a[q * n + k] = s * a_pk + c * a_qk;
+                    }
+                    rotate_cols(&mut v, n, p, q, c, s);
+                }
+            }
+        }
+        if !converged {
+            return Err(KTensorError::NoConvergence);
+        }
+
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&i, &j| a[i * n + i].partial_cmp(&a[j * n + j]).unwrap_or(cmp::Ordering::Equal));
+        Ok(SymmetricEigen {
+            values: order.iter().map(|&i| a[i * n + i]).collect(),
+            vectors: Matrix::new(Vec2(n, n), select_cols(&v, n, &order)),
+        })
+    }
+}
@@ -0,0 +1,123 @@
+use std::ops::{Add, Sub, Mul, Div, Neg};
+
+/// Numeric element of a `Tensor` used by the graph operations
+///
+/// Every activation, cost and initializer is written once against `Float`
+/// and works for any element type implementing it.
+pub trait Float: Copy + Default + Send + Sync + PartialOrd + Add<Output=Self> + Sub<Output=Self> + Mul<Output=Self> + Div<Output=Self> + Neg<Output=Self> {
+    /// Returns the additive identity
+    fn zero() -> Self;
+
+    /// Returns the multiplicative identity
+    fn one() -> Self;
+
+    /// Returns NaN
+    fn nan() -> Self;
+
+    /// Returns the difference between 1 and the next representable value
+    fn epsilon() -> Self;
+
+    /// Converts a `f64` to this type
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ktensor::math::Float;
+    /// assert_eq!(<f32 as Float>::from_f64(0.5), 0.5f32);
+    /// ```
+    fn from_f64(value: f64) -> Self;
+
+    /// Converts this value to a `f64`
+    fn to_f64(self) -> f64;
+
+    /// Converts a `usize` to this type
+    fn from_usize(value: usize) -> Self;
+
+    fn exp(self) -> Self;
+    fn ln(self) -> Self;
+    fn sqrt(self) -> Self;
+    fn abs(self) -> Self;
+    fn recip(self) -> Self;
+    fn powi(self, n: i32) -> Self;
+
+    /// Returns the maximum of two numbers, ignoring NaN
+    fn max(self, other: Self) -> Self;
+
+    /// Returns the minimum of two numbers, ignoring NaN
+    fn min(self, other: Self) -> Self;
+
+    fn is_nan(self) -> bool;
+}
+
+macro_rules! impl_float {
+    ($t:ident) => {
+        impl Float for $t {
+            fn zero() -> $t {
+                0.0
+            }
+
+            fn one() -> $t {
+                1.0
+            }
+
+            fn nan() -> $t {
+                $t::NAN
+            }
+
+            fn epsilon() -> $t {
+                $t::EPSILON
+            }
+
+            fn from_f64(value: f64) -> $t {
+                value as $t
+            }
+
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+
+            fn from_usize(value: usize) -> $t {
+                value as $t
+            }
+
+            fn exp(self) -> $t {
+                $t::exp(self)
+            }
+
+            fn ln(self) -> $t {
+                $t::ln(self)
+            }
+
+            fn sqrt(self) -> $t {
+                $t::sqrt(self)
+            }
+
+            fn abs(self) -> $t {
+                $t::abs(self)
+            }
+
+            fn recip(self) -> $t {
+                $t::recip(self)
+            }
+
+            fn powi(self, n: i32) -> $t {
+                $t::powi(self, n)
+            }
+
+            fn max(self, other: $t) -> $t {
+                $t::max(self, other)
+            }
+
+            fn min(self, other: $t) -> $t {
+                $t::min(self, other)
+            }
+
+            fn is_nan(self) -> bool {
+                $t::is_nan(self)
+            }
+        }
+    }
+}
+
+impl_float!(f32);
+impl_float!(f64);
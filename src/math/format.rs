@@ -0,0 +1,98 @@
+use std::fmt;
+use math::{Matrix, Vec2};
+
+/// number of items above which a `Matrix` is summarized
+const THRESHOLD: usize = 1000;
+/// rows and columns printed at each end of a summarized axis
+const EDGE_ITEMS: usize = 3;
+
+/// Returns the indices printed along an axis of length `len`, with `None` in place of the skipped middle
+fn shown(len: usize, summarize: bool) -> Vec<Option<usize>> {
+    if summarize && len > 2 * EDGE_ITEMS {
+        (0..EDGE_ITEMS).map(Some).chain(Some(None)).chain((len - EDGE_ITEMS..len).map(Some)).collect()
+    } else {
+        (0..len).map(Some).collect()
+    }
+}
+
+/// Writes the rows of `matrix` as nested brackets with right-aligned items
+///
+/// Rows after the first are indented by `indent` spaces, and `item` formats a value with the precision of the `Formatter`.
+/// Matricies of more than `THRESHOLD` items only show `EDGE_ITEMS` rows and columns at each end.
+pub(crate) fn write_grid<T, F>(f: &mut fmt::Formatter, matrix: &Matrix<T>, indent: usize, item: F) -> fmt::Result where T: Copy, F: Fn(T, Option<usize>) -> String {
+    let Vec2(row, col) = matrix.dim();
+    if row == 0 || col == 0 {
+        return write!(f, "[]");
+    }
+    let summarize = row * col > THRESHOLD;
+    let rows = shown(row, summarize);
+    let cols = shown(col, summarize);
+    let precision = f.precision();
+    let cells: Vec<Vec<String>> = rows.iter().map(|&i| match i {
+        Some(i) => cols.iter().map(|&j| match j {
+            Some(j) => item(matrix.get(Vec2(i, j)), precision),
+            None    => "...".to_string(),
+        }).collect(),
+        None    => Vec::new(),
+    }).collect();
+    let width = cells.iter().flat_map(|cells| cells.iter()).map(|cell| cell.len()).max().unwrap_or(0);
+
+    write!(f, "[")?;
+    for (i, cells) in cells.iter().enumerate() {
+        if i > 0 {
+            write!(f, ",\n{:indent$}", "", indent = indent + 1)?;
+        }
+        if cells.is_empty() {
+            write!(f, "...")?;
+            continue;
+        }
+        write!(f, "[")?;
+        for (j, cell) in cells.iter().enumerate() {
+            if j > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{:>width$}", cell, width = width)?;
+        }
+        write!(f, "]")?;
+    }
+    write!(f, "]")
+}
+
+impl <T> fmt::Display for Matrix<T> where T: Copy + fmt::Display {
+    /// Formats the rows of the `Matrix` like NumPy, summarizing large matricies
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let matrix = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), vec![0.5, 10.0, -2.25, 3.0, 4.0, 5.0]);
+    /// assert_eq!(format!("{}", matrix), "[[  0.5,    10, -2.25],\n [    3,     4,     5]]");
+    /// assert_eq!(format!("{:.1}", matrix), "[[ 0.5, 10.0, -2.2],\n [ 3.0,  4.0,  5.0]]");
+    /// let large = ktensor::math::Matrix::new(ktensor::math::Vec2(100, 100), vec![0; 10000]);
+    /// assert_eq!(format!("{}", large).lines().count(), 7);
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_grid(f, self, 0, |x, precision| match precision {
+            Some(p) => format!("{:.*}", p, x),
+            None    => format!("{}", x),
+        })
+    }
+}
+
+impl <T> fmt::Debug for Matrix<T> where T: Copy + fmt::Debug {
+    /// Formats the rows of the `Matrix` like a NumPy repr
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let matrix = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 2), vec![1.0, 2.0, 3.0, 4.0]);
+    /// assert_eq!(format!("{:?}", matrix), "Matrix([[1.0, 2.0],\n        [3.0, 4.0]])");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Matrix(")?;
+        write_grid(f, self, 7, |x, precision| match precision {
+            Some(p) => format!("{:.*?}", p, x),
+            None    => format!("{:?}", x),
+        })?;
+        write!(f, ")")
+    }
+}
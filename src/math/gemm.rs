@@ -0,0 +1,113 @@
+use std::cmp;
+use std::ops::{Add, Mul, Range};
+use math::{Vec2};
+use math::parallel;
+
+/// rows of `a` packed per block
+const BLOCK_M: usize = 64;
+/// shared dimension packed per block
+const BLOCK_K: usize = 256;
+/// columns of `b` packed per panel
+const BLOCK_N: usize = 256;
+
+/// A strided, read only view of a buffer laid out as a matrix
+pub struct Operand<'a, T: 'a> {
+    /// items of the matrix
+    pub buffer: &'a [T],
+    /// vector to get items from buffer
+    pub traverse_vec: Vec2,
+}
+
+impl <'a, T> Operand<'a, T> where T: Copy {
+    fn get(&self, i: usize, j: usize) -> T {
+        self.buffer[i * self.traverse_vec.0 + j * self.traverse_vec.1]
+    }
+
+    /// Copies the block `rows` x `cols` into `packed` in row-major order
+    ///
+    /// Row-major and transposed layouts are copied a contiguous run at a time.
+    fn pack(&self, rows: Range<usize>, cols: Range<usize>, packed: &mut Vec<T>) {
+        let Vec2(x, y) = self.traverse_vec;
+        let width = cols.end - cols.start;
+        packed.clear();
+        if y == 1 {
+            for i in rows {
+                let start = i * x + cols.start;
+                packed.extend_from_slice(&self.buffer[start..start + width]);
+            }
+        } else if x == 1 {
+            let height = rows.end - rows.start;
+            packed.resize(height * width, self.get(rows.start, cols.start));
+            for (j, col) in cols.enumerate() {
+                let start = col * y + rows.start;
+                for (i, &value) in self.buffer[start..start + height].iter().enumerate() {
+                    packed[i * width + j] = value;
+                }
+            }
+        } else {
+            for i in rows {
+                for j in cols.clone() {
+                    packed.push(self.get(i, j));
+                }
+            }
+        }
+    }
+}
+
+/// Multiplies `a` by `b`, returning the row-major buffer of the product
+///
+/// Every item of the product is summed in the same order as the textbook triple loop,
+/// so results do not depend on the block sizes or the number of threads.
+/// A shared dimension of 0 gives a product of `T::default()`, the zero of every numeric type.
+///
+/// # Arguments
+///
+/// - `a` - left operand
+/// - `b` - right operand
+/// - `Vec2` - rows and columns of `a`
+/// - `cols` - columns of `b`
+pub fn multiply<T>(a: &Operand<T>, b: &Operand<T>, Vec2(rows, depth): Vec2, cols: usize) -> Vec<T> where T: Mul<Output=T> + Add<Output=T> + Copy + Default + Send + Sync {
+    if depth == 0 {
+        return vec![T::default(); rows * cols];
+    }
+    parallel::collect(rows, depth * cols, |range| multiply_rows(a, b, range, depth, cols))
+}
+
+/// Computes the rows `range` of the product of `a` and `b`
+fn multiply_rows<T>(a: &Operand<T>, b: &Operand<T>, range: Range<usize>, depth: usize, cols: usize) -> Vec<T> where T: Mul<Output=T> + Add<Output=T> + Copy {
+    let offset = range.start;
+    let mut buffer = Vec::with_capacity((range.end - range.start) * cols);
+    for i in range.clone() {
+        let a_i = a.get(i, 0);
+        for j in 0..cols {
+            buffer.push(a_i * b.get(0, j));
+        }
+    }
+
+    let mut a_packed = Vec::with_capacity(BLOCK_M * BLOCK_K);
+    let mut b_packed = Vec::with_capacity(BLOCK_K * BLOCK_N);
+    for jj in (0..cols).step_by(BLOCK_N) {
+        let j_end = cmp::min(jj + BLOCK_N, cols);
+        let width = j_end - jj;
+        for kk in (1..depth).step_by(BLOCK_K) {
+            let k_end = cmp::min(kk + BLOCK_K, depth);
+            let height = k_end - kk;
+            b.pack(kk..k_end, jj..j_end, &mut b_packed);
+            for ii in range.clone().step_by(BLOCK_M) {
+                let i_end = cmp::min(ii + BLOCK_M, range.end);
+                a.pack(ii..i_end, kk..k_end, &mut a_packed);
+                for (i, a_row) in a_packed.chunks(height).enumerate() {
+                    let start = (ii - offset + i) * cols + jj;
+                    let c_row = &mut buffer[start..start + width];
+                    for (&a_ik, b_row) in a_row.iter().zip(b_packed.chunks(width)) {
+                        for (c, &b_kj) in c_row.iter_mut().zip(b_row.iter()) {
+                            *c = *c + a_ik * b_kj;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    buffer
+}
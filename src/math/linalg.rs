@@ -0,0 +1,327 @@
+use math::{Matrix, Vec2, Float};
+use error::{KTensorError};
+
+/// LU decomposition with partial pivoting, `PA = LU`
+///
+/// `L` is unit lower triangular and `U` is upper triangular, both packed into one row-major buffer.
+pub struct Lu<T> {
+    /// number of rows and columns
+    n: usize,
+    /// strictly lower part of `L` and upper part of `U`
+    packed: Vec<T>,
+    /// row of `A` moved to each row of `PA`
+    permutation: Vec<usize>,
+    /// determinant of `P`
+    sign: T,
+}
+
+impl <T> Lu<T> where T: Float {
+    /// Returns the `L` factor
+    pub fn l(&self) -> Matrix<T> {
+        let n = self.n;
+        Matrix::new(Vec2(n, n), (0..n * n).map(|k| {
+            let (i, j) = (k / n, k % n);
+            if i > j {
+                self.packed[k]
+            } else if i == j {
+                T::one()
+            } else {
+                T::zero()
+            }
+        }).collect())
+    }
+
+    /// Returns the `U` factor
+    pub fn u(&self) -> Matrix<T> {
+        let n = self.n;
+        Matrix::new(Vec2(n, n), (0..n * n).map(|k| if k / n <= k % n { self.packed[k] } else { T::zero() }).collect())
+    }
+
+    /// Returns the row of `A` moved to each row of `PA`
+    pub fn permutation(&self) -> &[usize] {
+        &self.permutation
+    }
+
+    /// Returns the determinant of `A`
+    pub fn det(&self) -> T {
+        (0..self.n).fold(self.sign, |det, i| det * self.packed[i * self.n + i])
+    }
+
+    /// Solves `AX = B`
+    ///
+    /// # Arguments
+    ///
+    /// - `b` - right hand side with one system per column
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let a = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 2), vec![0.0, 2.0, 1.0, 1.0]);
+    /// let lu = a.lu().unwrap();
+    /// let x = lu.solve(&ktensor::math::Matrix::new(ktensor::math::Vec2(2, 1), vec![4.0, 3.0])).unwrap();
+    /// assert_eq!(x.to_flattened(), vec![1.0, 2.0]);
+    /// ```
+    pub fn solve(&self, b: &Matrix<T>) -> Result<Matrix<T>, KTensorError> {
+        let n = self.n;
+        let Vec2(rows, cols) = b.dim();
+        if rows != n {
+            return Err(KTensorError::shape_mismatch(Vec2(n, cols), b.dim()));
+        }
+        let mut x = Vec::with_capacity(n * cols);
+        for &p in &self.permutation {
+            x.extend(b.row(p).iter());
+        }
+        forward_substitution(&self.packed, n, &mut x, cols, true);
+        backward_substitution(&self.packed, n, &mut x, cols);
+        Ok(Matrix::new(Vec2(n, cols), x))
+    }
+
+    /// Returns the inverse of `A`
+    pub fn inverse(&self) -> Matrix<T> {
+        let n = self.n;
+        let mut x = vec![T::zero(); n * n];
+        for (i, &p) in self.permutation.iter().enumerate() {
+            x[i * n + p] = T::one();
+        }
+        forward_substitution(&self.packed, n, &mut x, n, true);
+        backward_substitution(&self.packed, n, &mut x, n);
+        Matrix::new(Vec2(n, n), x)
+    }
+}
+
+/// Solves `LX = B` in place, where `L` is the lower triangle of the row-major `n x n` buffer `a`
+fn forward_substitution<T>(a: &[T], n: usize, x: &mut [T], cols: usize, unit_diagonal: bool) where T: Float {
+    for i in 0..n {
+        for k in 0..i {
+            let l_ik = a[i * n + k];
+            for j in 0..cols {
+                x[i * cols + j] = x[i * cols + j] - l_ik * x[k * cols + j];
+            }
+        }
+        if !unit_diagonal {
+            let l_ii = a[i * n + i];
+            for j in 0..cols {
+                x[i * cols + j] = x[i * cols + j] / l_ii;
+            }
+        }
+    }
+}
+
+/// Solves `UX = B` in place, where `U` is the upper triangle of the row-major `n x n` buffer `a`
+fn backward_substitution<T>(a: &[T], n: usize, x: &mut [T], cols: usize) where T: Float {
+    for i in (0..n).rev() {
+        for k in i + 1..n {
+            let u_ik = a[i * n + k];
+            for j in 0..cols {
+                x[i * cols + j] = x[i * cols + j] - u_ik * x[k * cols + j];
+            }
+        }
+        let u_ii = a[i * n + i];
+        for j in 0..cols {
+            x[i * cols + j] = x[i * cols + j] / u_ii;
+        }
+    }
+}
+
+impl <T> Matrix<T> where T: Float {
+    /// Returns the number of rows of a square `Matrix`
+    fn square(&self) -> Result<usize, KTensorError> {
+        let Vec2(row, col) = self.dim();
+        if row == col {
+            Ok(row)
+        } else {
+            Err(KTensorError::NotSquare(row, col))
+        }
+    }
+
+    /// Returns the largest magnitude of each column, which scales the tolerance of its pivot
+    fn column_scales(&self) -> Vec<T> {
+        let Vec2(row, col) = self.dim();
+        (0..col).map(|j| (0..row).fold(T::zero(), |scale, i| scale.max(self.get(Vec2(i, j)).abs()))).collect()
+    }
+
+    /// Returns the rows and values of the right hand side of a triangular system
+    fn triangular_system(&self, b: &Matrix<T>) -> Result<(usize, Vec<T>, Vec<T>), KTensorError> {
+        let n = self.square()?;
+        if b.dim().0 != n {
+            return Err(KTensorError::shape_mismatch(Vec2(n, b.dim().1), b.dim()));
+        }
+        let a: Vec<T> = self.iter().collect();
+        let scales = self.column_scales();
+        if (0..n).any(|i| negligible(a[i * n + i], scales[i], n)) {
+            return Err(KTensorError::Singular);
+        }
+        Ok((n, a, b.iter().collect()))
+    }
+
+    /// Decomposes a square `Matrix` into `PA = LU` with partial pivoting
+    ///
+    /// Fails with `KTensorError::Singular` when a pivot is within rounding error of 0 relative to the largest item of its column,
+    /// so badly scaled but regular matricies still decompose.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let a = ktensor::math::Matrix::new(ktensor::math::Vec2(3, 3), vec![2.0, 1.0, 1.0, 4.0, 3.0, 3.0, 8.0, 7.0, 9.0]);
+    /// let lu = a.lu().unwrap();
+    /// let pa: Vec<f64> = lu.permutation().iter().flat_map(|&p| a.row(p).to_flattened()).collect();
+    /// assert_eq!((&lu.l() * &lu.u()).to_flattened(), pa);
+    /// assert!(ktensor::math::Matrix::new(ktensor::math::Vec2(2, 2), vec![1.0, 2.0, 2.0, 4.0]).lu().is_err());
+    /// assert!(ktensor::math::Matrix::new(ktensor::math::Vec2(2, 2), vec![1e20, 0.0, 0.0, 1e-5]).lu().is_ok());
+    ///
+    /// // rank 2, although rounding leaves a last pivot of about 1e-16
+    /// let rank_deficient = ktensor::math::Matrix::new(ktensor::math::Vec2(3, 3), (1..10).map(|x| x as f64).collect());
+    /// assert_eq!(rank_deficient.lu().err(), Some(ktensor::KTensorError::Singular));
+    /// assert_eq!(rank_deficient.inverse().err(), Some(ktensor::KTensorError::Singular));
+    /// ```
+    pub fn lu(&self) -> Result<Lu<T>, KTensorError> {
+        let (lu, singular) = self.factor()?;
+        if singular {
+            Err(KTensorError::Singular)
+        } else {
+            Ok(lu)
+        }
+    }
+
+    /// Runs the elimination of `lu`, returning whether a pivot was negligible
+    ///
+    /// Columns with a pivot of exactly 0 or NaN are left uneliminated, so the diagonal still gives the determinant.
+    fn factor(&self) -> Result<(Lu<T>, bool), KTensorError> {
+        let n = self.square()?;
+        let mut a: Vec<T> = self.iter().collect();
+        let mut permutation: Vec<usize> = (0..n).collect();
+        let mut sign = T::one();
+        let mut singular = false;
+        let scales = self.column_scales();
+
+        for k in 0..n {
+            let p = (k..n).fold(k, |p, i| if a[i * n + k].abs() > a[p * n + k].abs() { i } else { p });
+            if negligible(a[p * n + k], scales[k], n) {
+                singular = true;
+            }
+            if a[p * n + k] == T::zero() || a[p * n + k].is_nan() {
+                continue;
+            }
+            if p != k {
+                for j in 0..n {
+                    a.swap(p * n + j, k * n + j);
+                }
+                permutation.swap(p, k);
+                sign = -sign;
+            }
+            let pivot = a[k * n + k];
+            for i in k + 1..n {
+                let factor = a[i * n + k] / pivot;
+                a[i * n + k] = factor;
+                for j in k + 1..n {
+                    a[i * n + j] = a[i * n + j] - factor * a[k * n + j];
+                }
+            }
+        }
+
+        Ok((Lu {
+            n,
+            packed: a,
+            permutation,
+            sign,
+        }, singular))
+    }
+
+    /// Solves `AX = B` for a square `Matrix` `A`
+    ///
+    /// # Arguments
+    ///
+    /// - `b` - right hand side with one system per column
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let a = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 2), vec![3.0f64, 1.0, 1.0, 2.0]);
+    /// let b = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 2), vec![9.0, 4.0, 8.0, 3.0]);
+    /// let x = a.solve(&b).unwrap();
+    /// for (x, y) in x.iter().zip(vec![2.0, 1.0, 3.0, 1.0]) {
+    ///     assert!((x - y).abs() < 1e-12);
+    /// }
+    /// let c = ktensor::math::Matrix::new(ktensor::math::Vec2(3, 1), vec![1.0, 2.0, 3.0]);
+    /// assert_eq!(a.solve(&c).err(), Some(ktensor::KTensorError::ShapeMismatch { lhs: vec![2, 1], rhs: vec![3, 1] }));
+    /// ```
+    pub fn solve(&self, b: &Matrix<T>) -> Result<Matrix<T>, KTensorError> {
+        self.lu()?.solve(b)
+    }
+
+    /// Returns the inverse of a square `Matrix`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let a = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 2), vec![4.0f64, 7.0, 2.0, 6.0]);
+    /// let inverse = a.inverse().unwrap();
+    /// for (x, y) in (&a * &inverse).iter().zip(vec![1.0, 0.0, 0.0, 1.0]) {
+    ///     assert!((x - y).abs() < 1e-12);
+    /// }
+    /// assert_eq!(ktensor::math::Matrix::new(ktensor::math::Vec2(1, 1), vec![0.0]).inverse().err(), Some(ktensor::KTensorError::Singular));
+    /// ```
+    pub fn inverse(&self) -> Result<Matrix<T>, KTensorError> {
+        Ok(self.lu()?.inverse())
+    }
+
+    /// Returns the determinant of a square `Matrix` as the signed product of its pivots, which is 0 when it is singular
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let a = ktensor::math::Matrix::new(ktensor::math::Vec2(3, 3), vec![2.0f64, 0.0, 1.0, 1.0, 3.0, 2.0, 1.0, 1.0, 2.0]);
+    /// assert!((a.det().unwrap() - 6.0).abs() < 1e-12);
+    /// assert_eq!(ktensor::math::Matrix::new(ktensor::math::Vec2(2, 2), vec![1.0, 2.0, 2.0, 4.0]).det(), Ok(0.0));
+    /// assert!(ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), vec![0.0; 6]).det().is_err());
+    /// let scaled = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 2), vec![1e20f64, 0.0, 0.0, 1e-5]);
+    /// assert!((scaled.det().unwrap() - 1e15).abs() < 1.0);
+    /// ```
+    pub fn det(&self) -> Result<T, KTensorError> {
+        let (lu, _) = self.factor()?;
+        Ok(lu.det())
+    }
+
+    /// Solves `LX = B` using the lower triangle of a square `Matrix`
+    ///
+    /// # Arguments
+    ///
+    /// - `b` - right hand side with one system per column
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let l = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 2), vec![2.0, 0.0, 1.0, 4.0]);
+    /// let x = l.solve_lower_triangular(&ktensor::math::Matrix::new(ktensor::math::Vec2(2, 1), vec![2.0, 9.0])).unwrap();
+    /// assert_eq!(x.to_flattened(), vec![1.0, 2.0]);
+    /// ```
+    pub fn solve_lower_triangular(&self, b: &Matrix<T>) -> Result<Matrix<T>, KTensorError> {
+        let (n, a, mut x) = self.triangular_system(b)?;
+        forward_substitution(&a, n, &mut x, b.dim().1, false);
+        Ok(Matrix::new(b.dim(), x))
+    }
+
+    /// Solves `UX = B` using the upper triangle of a square `Matrix`
+    ///
+    /// # Arguments
+    ///
+    /// - `b` - right hand side with one system per column
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let u = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 2), vec![2.0, 1.0, 0.0, 4.0]);
+    /// let x = u.solve_upper_triangular(&ktensor::math::Matrix::new(ktensor::math::Vec2(2, 1), vec![4.0, 8.0])).unwrap();
+    /// assert_eq!(x.to_flattened(), vec![1.0, 2.0]);
+    /// ```
+    pub fn solve_upper_triangular(&self, b: &Matrix<T>) -> Result<Matrix<T>, KTensorError> {
+        let (n, a, mut x) = self.triangular_system(b)?;
+        backward_substitution(&a, n, &mut x, b.dim().1);
+        Ok(Matrix::new(b.dim(), x))
+    }
+}
+
+/// Returns whether `pivot` is NaN or within the rounding error of `n` steps of elimination on a column of largest item `scale`
+fn negligible<T>(pivot: T, scale: T, n: usize) -> bool where T: Float {
+    pivot.is_nan() || pivot.abs() <= T::from_usize(n) * T::epsilon() * scale
+}
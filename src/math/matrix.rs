@@ -1,17 +1,57 @@
-use std::ops::{Add, Mul};
+use std::borrow::{Cow};
+use std::ops::{Add, Sub, Mul, Div, Neg, AddAssign, SubAssign, MulAssign};
+use std::sync::{Arc};
+use math::gemm::{self, Operand};
+use math::parallel;
+use math::{Float};
+use error::{KTensorError};
 
 /// A pair of coordinates
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct Vec2(pub usize, pub usize);
 
+impl Vec2 {
+    /// Returns the dimensions two broadcast matricies expand to, or `None` if they are incompatible
+    ///
+    /// Each pair of axes must be equal or one of them must be 1.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let ktensor::math::Vec2(x, y) = ktensor::math::Vec2(4, 1).broadcast(ktensor::math::Vec2(1, 3)).unwrap();
+    /// assert_eq!((x, y), (4, 3));
+    /// assert!(ktensor::math::Vec2(4, 2).broadcast(ktensor::math::Vec2(3, 2)).is_none());
+    /// ```
+    pub fn broadcast(self, rhs: Vec2) -> Option<Vec2> {
+        fn axis(a: usize, b: usize) -> Option<usize> {
+            if a == b || b == 1 {
+                Some(a)
+            } else if a == 1 {
+                Some(b)
+            } else {
+                None
+            }
+        }
+        match (axis(self.0, rhs.0), axis(self.1, rhs.1)) {
+            (Some(x), Some(y)) => Some(Vec2(x, y)),
+            _                  => None,
+        }
+    }
+}
+
 /// A structure of values
+///
+/// Clones, transposes and submatricies share the same reference counted buffer,
+/// which is copied only when a shared `Matrix` is mutated.
 pub struct Matrix<T> {
     /// vector of rows and columns
     dim: Vec2,
     /// vector to get items from buffer
     traverse_vec: Vec2,
+    /// index of the item at `(0, 0)` in the buffer
+    offset: usize,
     /// `Vector` of values in the `Matrix`
-    buffer: Vec<T>,
+    buffer: Arc<Vec<T>>,
 }
 
 impl <T> Matrix<T> {
@@ -32,13 +72,48 @@ impl <T> Matrix<T> {
         Matrix {
             dim: dimensions,
             traverse_vec: Vec2(y, 1),
-            buffer: buffer,
+            offset: 0,
+            buffer: Arc::new(buffer),
+        }
+    }
+
+    /// Returns a new `Matrix`, or an error if `buffer` does not have one value per item
+    ///
+    /// # Arguments
+    ///
+    /// - `dimensions` - dimensions of `Matrix`
+    /// - `buffer` - `Vec` of values
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// assert!(ktensor::math::Matrix::try_new(ktensor::math::Vec2(2, 3), (0..6).collect::<Vec<i32>>()).is_ok());
+    /// assert!(ktensor::math::Matrix::try_new(ktensor::math::Vec2(2, 3), (0..5).collect::<Vec<i32>>()).is_err());
+    /// ```
+    pub fn try_new(dimensions: Vec2, buffer: Vec<T>) -> Result<Matrix<T>, KTensorError> {
+        let Vec2(x, y) = dimensions;
+        if x * y != buffer.len() {
+            return Err(KTensorError::ShapeMismatch {
+                lhs: vec![x, y],
+                rhs: vec![buffer.len()],
+            });
         }
+        Ok(Matrix::new(dimensions, buffer))
+    }
+
+    /// Returns the dimensions `self` and `rhs` broadcast to, or a `ShapeMismatch`
+    fn try_broadcast<U>(&self, rhs: &Matrix<U>) -> Result<Vec2, KTensorError> {
+        self.dim.broadcast(rhs.dim).ok_or_else(|| KTensorError::shape_mismatch(self.dim, rhs.dim))
     }
 
     /// Returns the total number of items in the `Matrix`
     pub fn len(&self) -> usize {
-        self.buffer.len()
+        self.dim.0 * self.dim.1
+    }
+
+    /// Returns `true` if the `Matrix` contains no items
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
     }
 
     /// Returns the dimensions of the `Matrix`
@@ -46,23 +121,145 @@ impl <T> Matrix<T> {
         self.dim
     }
 
-    /// Gives ownership to the buffer
+    /// Returns `true` if the items are laid out in row-major order without gaps
     ///
     /// # Example
     ///
     /// ```
-    /// let vector = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), (0..6).collect()).to_flattened();
-    /// let result = vec![0, 1, 2, 3, 4, 5];
-    /// for (&i, &j) in vector.iter().zip(result.iter()) {
+    /// let matrix = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), (0..6).collect());
+    /// assert!(matrix.is_contiguous());
+    /// assert!(!matrix.transpose().is_contiguous());
+    /// assert!(matrix.row(1).is_contiguous());
+    /// ```
+    pub fn is_contiguous(&self) -> bool {
+        let Vec2(x, y) = self.dim;
+        let Vec2(t_x, t_y) = self.traverse_vec;
+        (y <= 1 || t_y == 1) && (x <= 1 || t_x == y)
+    }
+
+    /// Returns the part of the shared buffer spanned by the `Matrix` in memory order
+    ///
+    /// The slice holds exactly the items of the `Matrix` in row-major order only if it `is_contiguous`,
+    /// views may skip, repeat or reorder items. Use `buffer` for the items themselves.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let matrix = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), (0..6).collect());
+    /// assert_eq!(matrix.col(1).storage(), &[1, 2, 3, 4]);
+    /// ```
+    pub fn storage(&self) -> &[T] {
+        if self.is_empty() {
+            return &[];
+        }
+        let Vec2(x, y) = self.dim;
+        let last = self.offset + (x - 1) * self.traverse_vec.0 + (y - 1) * self.traverse_vec.1;
+        &self.buffer[self.offset..last + 1]
+    }
+
+    fn operand(&self) -> Operand<'_, T> {
+        Operand {
+            buffer: &self.buffer[self.offset..],
+            traverse_vec: self.traverse_vec,
+        }
+    }
+
+    /// Returns a `Matrix` sharing the buffer, with the item at `(0, 0)` moved to `offset`
+    fn view(&self, dimensions: Vec2, traverse_vec: Vec2, offset: usize) -> Matrix<T> {
+        Matrix {
+            dim: dimensions,
+            traverse_vec,
+            offset,
+            buffer: self.buffer.clone(),
+        }
+    }
+
+    /// Returns Matrix of values from [vec1 to vec2) (inclusive and exclusive) without copying
+    ///
+    /// # Arguments
+    ///
+    /// - `Vec2` - coordinates of the first corner
+    /// - `Vec2` - coordinates of the second corner
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let matrix = ktensor::math::Matrix::new(ktensor::math::Vec2(3, 3), (0..9).collect());
+    /// let slice = matrix.get_submatrix(ktensor::math::Vec2(1, 1), ktensor::math::Vec2(3, 3)).to_flattened();
+    /// let result = vec![4, 5, 7, 8];
+    /// for (&i, &j) in slice.iter().zip(result.iter()) {
     ///     assert_eq!(i, j);
     /// }
     /// ```
-    pub fn to_flattened(self) -> Vec<T> {
-        self.buffer
+    pub fn get_submatrix(&self, Vec2(x1, y1): Vec2, Vec2(x2, y2): Vec2) -> Matrix<T> {
+        assert!(x1 < x2 && x2 <= self.dim.0);
+        assert!(y1 < y2 && y2 <= self.dim.1);
+        let offset = self.offset + x1 * self.traverse_vec.0 + y1 * self.traverse_vec.1;
+        self.view(Vec2(x2-x1, y2-y1), self.traverse_vec, offset)
+    }
+
+    /// Returns row `i` as a `1 x n` `Matrix` without copying
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let matrix = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), (0..6).collect());
+    /// assert_eq!(matrix.row(1).get(ktensor::math::Vec2(0, 2)), 5);
+    /// ```
+    pub fn row(&self, i: usize) -> Matrix<T> {
+        assert!(i < self.dim.0);
+        self.view(Vec2(1, self.dim.1), self.traverse_vec, self.offset + i * self.traverse_vec.0)
+    }
+
+    /// Returns column `j` as a `n x 1` `Matrix` without copying
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let matrix = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), (0..6).collect());
+    /// assert_eq!(matrix.col(2).get(ktensor::math::Vec2(1, 0)), 5);
+    /// ```
+    pub fn col(&self, j: usize) -> Matrix<T> {
+        assert!(j < self.dim.1);
+        self.view(Vec2(self.dim.0, 1), self.traverse_vec, self.offset + j * self.traverse_vec.1)
+    }
+
+    /// Returns the `Matrix` repeated along its axes of length 1 to `dimensions` without copying
+    ///
+    /// # Arguments
+    ///
+    /// - `dimensions` - dimensions to expand to
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let matrix = ktensor::math::Matrix::new(ktensor::math::Vec2(1, 3), (0..3).collect());
+    /// let matrix = matrix.broadcast(ktensor::math::Vec2(2, 3));
+    /// assert_eq!(matrix.to_flattened(), vec![0, 1, 2, 0, 1, 2]);
+    /// ```
+    pub fn broadcast(&self, dimensions: Vec2) -> Matrix<T> {
+        let Vec2(x, y) = self.dim;
+        let Vec2(dim_x, dim_y) = dimensions;
+        assert!(x == dim_x || x == 1, "cannot broadcast {} rows to {}", x, dim_x);
+        assert!(y == dim_y || y == 1, "cannot broadcast {} columns to {}", y, dim_y);
+        let traverse_x = if x == dim_x { self.traverse_vec.0 } else { 0 };
+        let traverse_y = if y == dim_y { self.traverse_vec.1 } else { 0 };
+        self.view(dimensions, Vec2(traverse_x, traverse_y), self.offset)
     }
 
-    pub fn buffer(&self) -> &Vec<T> {
-        &self.buffer
+    /// Returns the transposed `Matrix` without copying
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let vector = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), (0..6).collect());
+    /// let vector = vector.transpose();
+    /// assert_eq!(vector.get(ktensor::math::Vec2(2, 0)), 2);
+    /// ```
+    pub fn transpose(&self) -> Matrix<T> {
+        let Vec2(dim_x, dim_y) = self.dim;
+        let Vec2(x, y) = self.traverse_vec;
+        self.view(Vec2(dim_y, dim_x), Vec2(y, x), self.offset)
     }
 
     /// Returns `Vec` of indicies from `(0, 0)` to `(x, y)` (inclusive and exclusive)
@@ -119,6 +316,26 @@ impl <T> Matrix<T> {
 }
 
 impl <T> Matrix<T> where T: Copy {
+    /// Returns the items of the `Matrix` in row-major order
+    ///
+    /// Items are borrowed if the `Matrix` `is_contiguous` and copied out of other views.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let matrix = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), (0..6).collect());
+    /// assert_eq!(&*matrix.buffer(), &[0, 1, 2, 3, 4, 5]);
+    /// assert_eq!(&*matrix.transpose().buffer(), &[0, 3, 1, 4, 2, 5]);
+    /// assert_eq!(&*matrix.col(1).buffer(), &[1, 4]);
+    /// ```
+    pub fn buffer(&self) -> Cow<'_, [T]> {
+        if self.is_contiguous() {
+            Cow::Borrowed(self.storage())
+        } else {
+            Cow::Owned(self.iter().collect())
+        }
+    }
+
     /// Returns value at `Vec2`
     ///
     /// # Arguments
@@ -133,70 +350,270 @@ impl <T> Matrix<T> where T: Copy {
     /// assert_eq!(matrix.get(ktensor::math::Vec2(1, 2)), 5);
     /// ```
     pub fn get(&self, Vec2(x, y): Vec2) -> T {
-        self.buffer[x * self.traverse_vec.0 + y * self.traverse_vec.1]
+        self.buffer[self.offset + x * self.traverse_vec.0 + y * self.traverse_vec.1]
     }
 
-    /// Returns Matrix of values from [vec1 to vec2) (inclusive and exclusive)
+    /// Returns an iterator over the items in row-major order, regardless of the layout of the buffer
     ///
-    /// # Arguments
+    /// # Example
     ///
-    /// - `Vec2` - coordinates of the first corner
-    /// - `Vec2` - coordinates of the second corner
+    /// ```
+    /// let matrix = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), (0..6).collect()).transpose();
+    /// let items: Vec<i32> = matrix.iter().collect();
+    /// assert_eq!(items, vec![0, 3, 1, 4, 2, 5]);
+    /// ```
+    pub fn iter(&self) -> impl ExactSizeIterator<Item=T> + '_ {
+        let Vec2(_, y) = self.dim;
+        (0..self.len()).map(move |k| self.get(Vec2(k / y, k % y)))
+    }
+
+    /// Returns an iterator over the rows as `1 x n` matricies without copying
     ///
     /// # Example
     ///
     /// ```
-    /// let matrix = ktensor::math::Matrix::new(ktensor::math::Vec2(3, 3), (0..9).collect());
-    /// let slice = matrix.get_submatrix(ktensor::math::Vec2(1, 1), ktensor::math::Vec2(3, 3)).to_flattened();
-    /// let result = vec![4, 5, 7, 8];
-    /// for (&i, &j) in slice.iter().zip(result.iter()) {
+    /// let matrix = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), (0..6).collect());
+    /// let sums: Vec<i32> = matrix.rows().map(|row| row.iter().sum()).collect();
+    /// assert_eq!(sums, vec![3, 12]);
+    /// ```
+    pub fn rows(&self) -> impl ExactSizeIterator<Item=Matrix<T>> + '_ {
+        (0..self.dim.0).map(move |i| self.row(i))
+    }
+
+    /// Returns an iterator over the columns as `n x 1` matricies without copying
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let matrix = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), (0..6).collect());
+    /// let sums: Vec<i32> = matrix.cols().map(|col| col.iter().sum()).collect();
+    /// assert_eq!(sums, vec![3, 5, 7]);
+    /// ```
+    pub fn cols(&self) -> impl ExactSizeIterator<Item=Matrix<T>> + '_ {
+        (0..self.dim.1).map(move |j| self.col(j))
+    }
+
+    /// Gives ownership to the values in row-major order
+    ///
+    /// The buffer is moved out without copying if it is contiguous and not shared.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let vector = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), (0..6).collect()).to_flattened();
+    /// let result = vec![0, 1, 2, 3, 4, 5];
+    /// for (&i, &j) in vector.iter().zip(result.iter()) {
     ///     assert_eq!(i, j);
     /// }
+    /// let vector = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), (0..6).collect()).transpose().to_flattened();
+    /// assert_eq!(vector, vec![0, 3, 1, 4, 2, 5]);
     /// ```
-    pub fn get_submatrix(&self, Vec2(x1, y1): Vec2, Vec2(x2, y2): Vec2) -> Matrix<T> {
-        assert!(x1 < x2);
-        assert!(y1 < y2);
-        let mut buf = Vec::<T>::with_capacity((x2-x1) * (y2-y1));
-        for i in x1..x2 {
-            for j in y1..y2 {
-                buf.push(self.get(Vec2(i, j)));
+    pub fn to_flattened(self) -> Vec<T> {
+        if self.is_contiguous() && self.offset == 0 && self.buffer.len() == self.len() {
+            match Arc::try_unwrap(self.buffer) {
+                Ok(buffer)  => buffer,
+                Err(shared) => (*shared).clone(),
             }
+        } else {
+            self.collect_items()
         }
+    }
 
-        Matrix {
-            dim: Vec2(x2-x1, y2-y1),
-            traverse_vec: Vec2(y2-y1, 1),
-            buffer: buf,
+    /// Returns the items in row-major order viewed with new dimensions
+    ///
+    /// The buffer is shared without copying if the `Matrix` is contiguous, and copied otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// - `dimensions` - new dimensions with the same number of items
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let matrix = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), (0..6).collect());
+    /// let reshaped = matrix.reshape(ktensor::math::Vec2(3, 2)).unwrap();
+    /// assert_eq!(reshaped.get(ktensor::math::Vec2(2, 0)), 4);
+    /// let reshaped = matrix.transpose().reshape(ktensor::math::Vec2(1, 6)).unwrap();
+    /// assert_eq!(reshaped.to_flattened(), vec![0, 3, 1, 4, 2, 5]);
+    /// assert!(matrix.reshape(ktensor::math::Vec2(4, 2)).is_err());
+    /// ```
+    pub fn reshape(&self, dimensions: Vec2) -> Result<Matrix<T>, KTensorError> {
+        let Vec2(x, y) = dimensions;
+        if x * y != self.len() {
+            return Err(KTensorError::shape_mismatch(self.dim, dimensions));
+        }
+        if self.is_contiguous() {
+            Ok(self.view(dimensions, Vec2(y, 1), self.offset))
+        } else {
+            Ok(Matrix::new(dimensions, self.collect_items()))
         }
     }
 
-    /// Consumes `Matrix` and returns transposed `Matrix`
+    /// Returns the items in row-major order as a new `Vec`
+    fn collect_items(&self) -> Vec<T> {
+        self.iter().collect()
+    }
+
+    /// Returns the items in row-major order for mutation
+    ///
+    /// The buffer is copied first if it is shared with another `Matrix` or is not contiguous.
     ///
     /// # Example
     ///
     /// ```
-    /// let vector = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), (0..6).collect());
-    /// let vector = vector.transpose();
-    /// assert_eq!(vector.get(ktensor::math::Vec2(2, 0)), 2);
+    /// let matrix1 = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), (0..6).collect());
+    /// let mut matrix2 = matrix1.transpose();
+    /// matrix2.buffer_mut()[1] = 10;
+    /// assert_eq!(matrix1.get(ktensor::math::Vec2(1, 0)), 3);
+    /// assert_eq!(matrix2.get(ktensor::math::Vec2(0, 1)), 10);
     /// ```
-    pub fn transpose(&self) -> Matrix<T> {
-        let Vec2(dim_x, dim_y) = self.dim;
-        let Vec2(x, y) = self.traverse_vec;
-        Matrix {
-            dim: Vec2(dim_y, dim_x),
-            traverse_vec: Vec2(y, x),
-            buffer: self.buffer.clone(),
+    pub fn buffer_mut(&mut self) -> &mut [T] {
+        if !(self.is_contiguous() && self.offset == 0 && self.buffer.len() == self.len()) {
+            let buffer = self.collect_items();
+            *self = Matrix::new(self.dim, buffer);
         }
+        Arc::make_mut(&mut self.buffer).as_mut_slice()
     }
 }
 
-impl <T> Clone for Matrix<T> where T: Copy {
-    fn clone(&self) -> Matrix<T> {
-        Matrix {
-            dim: self.dim,
-            traverse_vec: self.traverse_vec,
-            buffer: self.buffer.clone(),
+impl <T> Matrix<T> where T: Add<Output=T> + Copy {
+    /// Sums items along the axes broadcast from `dimensions`, reversing `broadcast`
+    ///
+    /// # Arguments
+    ///
+    /// - `dimensions` - dimensions to reduce to, each axis is either kept or 1
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let matrix = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), (0..6).collect());
+    /// assert_eq!(matrix.sum_to(ktensor::math::Vec2(1, 3)).to_flattened(), vec![3, 5, 7]);
+    /// assert_eq!(matrix.sum_to(ktensor::math::Vec2(2, 1)).to_flattened(), vec![3, 12]);
+    /// assert_eq!(matrix.sum_to(ktensor::math::Vec2(1, 1)).to_flattened(), vec![15]);
+    /// ```
+    pub fn sum_to(&self, dimensions: Vec2) -> Matrix<T> {
+        let Vec2(x, y) = self.dim;
+        let Vec2(dim_x, dim_y) = dimensions;
+        assert!(dim_x == x || dim_x == 1, "cannot reduce {} rows to {}", x, dim_x);
+        assert!(dim_y == y || dim_y == 1, "cannot reduce {} columns to {}", y, dim_y);
+        if dim_x == x && dim_y == y {
+            return self.clone();
+        }
+
+        let mut buffer = Vec::with_capacity(dim_x * dim_y);
+        for i in 0..dim_x {
+            let rows = if dim_x == x { i..i + 1 } else { 0..x };
+            for j in 0..dim_y {
+                let cols = if dim_y == y { j..j + 1 } else { 0..y };
+                let mut sum = self.get(Vec2(rows.start, cols.start));
+                for k in rows.clone() {
+                    for l in cols.clone() {
+                        if k != rows.start || l != cols.start {
+                            sum = sum + self.get(Vec2(k, l));
+                        }
+                    }
+                }
+                buffer.push(sum);
+            }
+        }
+        Matrix::new(dimensions, buffer)
+    }
+}
+
+impl <T> Matrix<T> where T: Copy + Send + Sync {
+    /// Returns a `Matrix` of `f` applied to every item in row-major order, splitting rows across worker threads
+    ///
+    /// # Arguments
+    ///
+    /// - `f` - function of an item
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let matrix = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), (0..6).collect()).transpose();
+    /// let matrix = matrix.map(|a| a * 2);
+    /// assert_eq!(matrix.to_flattened(), vec![0, 6, 2, 8, 4, 10]);
+    /// ```
+    pub fn map<U, F>(&self, f: F) -> Matrix<U> where U: Send, F: Fn(T) -> U + Sync {
+        let Vec2(row, col) = self.dim;
+        let buffer = parallel::collect(row, col, |range| {
+            let mut buf = Vec::with_capacity((range.end - range.start) * col);
+            for i in range {
+                for j in 0..col {
+                    buf.push(f(self.get(Vec2(i, j))));
+                }
+            }
+            buf
+        });
+        Matrix::new(self.dim, buffer)
+    }
+
+    /// Returns a `Matrix` of `f` applied to pairs of items of broadcast matricies, splitting rows across worker threads
+    ///
+    /// # Arguments
+    ///
+    /// - `rhs` - another matrix
+    /// - `f` - function of an item of `self` and an item of `rhs`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let matrix1 = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), (0..6).collect());
+    /// let matrix2 = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 1), vec![true, false]);
+    /// let matrix3 = matrix1.zip_map(&matrix2, |a, b| if b { a } else { 0 });
+    /// assert_eq!(matrix3.to_flattened(), vec![0, 1, 2, 0, 0, 0]);
+    /// ```
+    pub fn zip_map<U, V, F>(&self, rhs: &Matrix<U>, f: F) -> Matrix<V> where U: Copy + Send + Sync, V: Send, F: Fn(T, U) -> V + Sync {
+        let dim = match self.dim.broadcast(rhs.dim) {
+            Some(dim) => dim,
+            None      => panic!("cannot broadcast matricies of dimensions ({}, {}) and ({}, {})", self.dim.0, self.dim.1, rhs.dim.0, rhs.dim.1),
+        };
+        let lhs = self.broadcast(dim);
+        let rhs = rhs.broadcast(dim);
+        let Vec2(row, col) = dim;
+        let buffer = parallel::collect(row, col, |range| {
+            let mut buf = Vec::with_capacity((range.end - range.start) * col);
+            for i in range {
+                for j in 0..col {
+                    buf.push(f(lhs.get(Vec2(i, j)), rhs.get(Vec2(i, j))));
+                }
+            }
+            buf
+        });
+        Matrix::new(dim, buffer)
+    }
+
+    /// Combines items with a `rhs` broadcast to the dimensions of this `Matrix` in place
+    fn update_items<F>(&mut self, rhs: &Matrix<T>, f: F) where F: Fn(T, T) -> T + Sync {
+        let Vec2(x, y) = self.dim;
+        match self.dim.broadcast(rhs.dim) {
+            Some(Vec2(dim_x, dim_y)) if dim_x == x && dim_y == y => (),
+            _ => panic!("cannot broadcast matrix of dimensions ({}, {}) into ({}, {})", rhs.dim.0, rhs.dim.1, x, y),
         }
+        let rhs = rhs.broadcast(self.dim);
+        parallel::update(self.buffer_mut(), y, |start, rows| {
+            for (i, row) in rows.chunks_mut(y).enumerate() {
+                for (j, item) in row.iter_mut().enumerate() {
+                    *item = f(*item, rhs.get(Vec2(start + i, j)));
+                }
+            }
+        });
+    }
+
+    /// Applies `f` to every item in place
+    fn update_map<F>(&mut self, f: F) where F: Fn(T) -> T + Sync {
+        let Vec2(_, y) = self.dim;
+        parallel::update(self.buffer_mut(), y, |_, rows| {
+            for item in rows.iter_mut() {
+                *item = f(*item);
+            }
+        });
+    }
+}
+
+impl <T> Clone for Matrix<T> {
+    fn clone(&self) -> Matrix<T> {
+        self.view(self.dim, self.traverse_vec, self.offset)
     }
 }
 
@@ -205,7 +622,7 @@ impl <T> Clone for Matrix<T> where T: Copy {
 // Addition //
 //////////////
 
-impl <T> Add<Matrix<T>> for Matrix<T> where T: Add<Output=T> + Copy {
+impl <T> Add<Matrix<T>> for Matrix<T> where T: Add<Output=T> + Copy + Send + Sync {
     type Output = Matrix<T>;
 
     /// Add Matricies
@@ -224,22 +641,14 @@ impl <T> Add<Matrix<T>> for Matrix<T> where T: Add<Output=T> + Copy {
     /// assert_eq!(matrix3.get(ktensor::math::Vec2(0, 0)), 5.0);
     /// ```
     fn add(self, rhs: Matrix<T>) -> Matrix<T> {
-        assert_eq!(self.dim.0, rhs.dim.0);
-        assert_eq!(self.dim.1, rhs.dim.1);
-        let mut buffer = Vec::with_capacity(self.len());
-        for i in 0..self.dim.0 {
-            for j in 0..self.dim.1 {
-                buffer.push(self.get(Vec2(i, j)) + rhs.get(Vec2(i, j)));
-            }
-        }
-        Matrix::new(self.dim, buffer)
+        &self + &rhs
     }
 }
 
-impl <'a, 'b, T> Add<&'b Matrix<T>> for &'a Matrix<T> where T: Add<Output=T> + Copy {
+impl <T> Add<&Matrix<T>> for &Matrix<T> where T: Add<Output=T> + Copy + Send + Sync {
     type Output = Matrix<T>;
 
-    /// Add Matricies by reference
+    /// Add Matricies by reference, broadcasting axes of length 1
     ///
     /// # Arguments
     ///
@@ -255,21 +664,16 @@ impl <'a, 'b, T> Add<&'b Matrix<T>> for &'a Matrix<T> where T: Add<Output=T> + C
     /// assert_eq!(matrix1.get(ktensor::math::Vec2(0, 0)), 0.0);
     /// assert_eq!(matrix2.get(ktensor::math::Vec2(0, 0)), 5.0);
     /// assert_eq!(matrix3.get(ktensor::math::Vec2(0, 0)), 5.0);
+    /// let bias = ktensor::math::Matrix::new(ktensor::math::Vec2(1, 3), vec![1.0, 2.0, 3.0]);
+    /// let matrix4 = &matrix1 + &bias;
+    /// assert_eq!(matrix4.get(ktensor::math::Vec2(1, 2)), 8.0);
     /// ```
-    fn add(self, rhs: &'b Matrix<T>) -> Matrix<T> {
-        assert_eq!(self.dim.0, rhs.dim.0);
-        assert_eq!(self.dim.1, rhs.dim.1);
-        let mut buffer = Vec::with_capacity(self.len());
-        for i in 0..self.dim.0 {
-            for j in 0..self.dim.1 {
-                buffer.push(self.get(Vec2(i, j)) + rhs.get(Vec2(i, j)));
-            }
-        }
-        Matrix::new(self.dim, buffer)
+    fn add(self, rhs: &Matrix<T>) -> Matrix<T> {
+        self.zip_map(rhs, |a, b| a + b)
     }
 }
 
-impl <T> Add<T> for Matrix<T> where T: Add<Output=T> + Copy {
+impl <T> Add<T> for Matrix<T> where T: Add<Output=T> + Copy + Send + Sync {
     type Output = Matrix<T>;
 
     /// Add Matrix and a constant
@@ -288,15 +692,11 @@ impl <T> Add<T> for Matrix<T> where T: Add<Output=T> + Copy {
     /// assert_eq!(matrix2.get(ktensor::math::Vec2(0, 0)), 1.0);
     /// ```
     fn add(self, rhs: T) -> Matrix<T> {
-        let mut buffer = Vec::with_capacity(self.len());
-        for &i in self.buffer.iter() {
-            buffer.push(i + rhs);
-        }
-        Matrix::new(self.dim, buffer)
+        &self + &rhs
     }
 }
 
-impl <'a, 'b, T> Add<&'b T> for &'a Matrix<T> where T: Add<Output=T> + Copy {
+impl <T> Add<&T> for &Matrix<T> where T: Add<Output=T> + Copy + Send + Sync {
     type Output = Matrix<T>;
 
     /// Add Matrix and a constant
@@ -316,24 +716,20 @@ impl <'a, 'b, T> Add<&'b T> for &'a Matrix<T> where T: Add<Output=T> + Copy {
     /// assert_eq!(matrix2.get(ktensor::math::Vec2(0, 0)), 1.0);
     /// assert_eq!(float, 1.0);
     /// ```
-    fn add(self, &rhs: &'b T) -> Matrix<T> {
-        let mut buffer = Vec::with_capacity(self.len());
-        for &i in self.buffer.iter() {
-            buffer.push(i + rhs);
-        }
-        Matrix::new(self.dim, buffer)
+    fn add(self, &rhs: &T) -> Matrix<T> {
+        self.map(|a| a + rhs)
     }
 }
 
 
-/////////////////////
-// Multiplication  //
-/////////////////////
+//////////////////
+// Subtraction  //
+//////////////////
 
-impl <T> Mul<Matrix<T>> for Matrix<T> where T: Mul<Output=T> + Add<Output=T> + Copy {
+impl <T> Sub<Matrix<T>> for Matrix<T> where T: Sub<Output=T> + Copy + Send + Sync {
     type Output = Matrix<T>;
 
-    /// Multiply Matricies
+    /// Subtract Matricies elementwise, broadcasting axes of length 1
     ///
     /// # Arguments
     ///
@@ -344,30 +740,118 @@ impl <T> Mul<Matrix<T>> for Matrix<T> where T: Mul<Output=T> + Add<Output=T> + C
     ///
     /// ```
     /// let matrix1 = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), (0..6).map(|i| i as f64).collect());
-    /// let matrix2 = ktensor::math::Matrix::new(ktensor::math::Vec2(3, 2), (0..6).map(|i| i as f64).rev().collect());
-    /// let matrix3 = matrix1 * matrix2;
-    /// assert_eq!(matrix3.len(), 4);
-    /// assert_eq!(matrix3.get(ktensor::math::Vec2(0, 0)), 5.0);
+    /// let matrix2 = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), (1..7).map(|i| i as f64).rev().collect());
+    /// let matrix3 = matrix1 - matrix2;
+    /// assert_eq!(matrix3.get(ktensor::math::Vec2(0, 2)), -2.0);
+    /// ```
+    fn sub(self, rhs: Matrix<T>) -> Matrix<T> {
+        &self - &rhs
+    }
+}
+
+impl <T> Sub<&Matrix<T>> for &Matrix<T> where T: Sub<Output=T> + Copy + Send + Sync {
+    type Output = Matrix<T>;
+
+    /// Subtract Matricies elementwise by reference, broadcasting axes of length 1
+    ///
+    /// # Arguments
+    ///
+    /// - `self` - this matrix reference
+    /// - `rhs` - another matrix reference
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let matrix1 = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), (0..6).map(|i| i as f64).collect());
+    /// let matrix2 = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), (1..7).map(|i| i as f64).rev().collect());
+    /// let matrix3 = &matrix1 - &matrix2;
+    /// assert_eq!(matrix1.get(ktensor::math::Vec2(0, 2)), 2.0);
+    /// assert_eq!(matrix2.get(ktensor::math::Vec2(0, 2)), 4.0);
+    /// assert_eq!(matrix3.get(ktensor::math::Vec2(0, 2)), -2.0);
+    /// ```
+    fn sub(self, rhs: &Matrix<T>) -> Matrix<T> {
+        self.zip_map(rhs, |a, b| a - b)
+    }
+}
+
+impl <T> Sub<T> for Matrix<T> where T: Sub<Output=T> + Copy + Send + Sync {
+    type Output = Matrix<T>;
+
+    /// Subtract Matrix and a constant
+    ///
+    /// # Arguments
+    ///
+    /// - `self` - this matrix
+    /// - `rhs` - a constant
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let matrix1 = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), (0..6).map(|i| i as f64).collect());
+    /// let float = 2.0;
+    /// let matrix2 = matrix1 - float;
+    /// assert_eq!(matrix2.get(ktensor::math::Vec2(0, 2)), 0.0);
+    /// ```
+    fn sub(self, rhs: T) -> Matrix<T> {
+        &self - &rhs
+    }
+}
+
+impl <T> Sub<&T> for &Matrix<T> where T: Sub<Output=T> + Copy + Send + Sync {
+    type Output = Matrix<T>;
+
+    /// Subtract Matrix and a constant
+    ///
+    /// # Arguments
+    ///
+    /// - `self` - this matrix reference
+    /// - `rhs` - a constant reference
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let matrix1 = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), (0..6).map(|i| i as f64).collect());
+    /// let float = 2.0;
+    /// let matrix2 = &matrix1 - &float;
+    /// assert_eq!(matrix1.get(ktensor::math::Vec2(0, 2)), 2.0);
+    /// assert_eq!(matrix2.get(ktensor::math::Vec2(0, 2)), 0.0);
+    /// assert_eq!(float, 2.0);
+    /// ```
+    fn sub(self, &rhs: &T) -> Matrix<T> {
+        self.map(|a| a - rhs)
+    }
+}
+
+
+/////////////////////
+// Multiplication  //
+/////////////////////
+
+impl <T> Mul<Matrix<T>> for Matrix<T> where T: Mul<Output=T> + Add<Output=T> + Copy + Default + Send + Sync {
+    type Output = Matrix<T>;
+
+    /// Multiply Matricies
+    ///
+    /// # Arguments
+    ///
+    /// - `self` - this matrix
+    /// - `rhs` - another matrix
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let matrix1 = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), (0..6).map(|i| i as f64).collect());
+    /// let matrix2 = ktensor::math::Matrix::new(ktensor::math::Vec2(3, 2), (0..6).map(|i| i as f64).rev().collect());
+    /// let matrix3 = matrix1 * matrix2;
+    /// assert_eq!(matrix3.len(), 4);
+    /// assert_eq!(matrix3.get(ktensor::math::Vec2(0, 0)), 5.0);
     /// ```
     fn mul(self, rhs: Matrix<T>) -> Matrix<T> {
-        let Vec2(x, y) = self.dim;
-        let Vec2(x2, y2) = rhs.dim;
-        assert_eq!(y, x2);
-        let mut buffer = Vec::with_capacity(x * y2);
-        for i in 0..x {
-            for j in 0..y2 {
-                let mut sum = self.get(Vec2(i, 0)) * rhs.get(Vec2(0, j));
-                for k in 1..y {
-                    sum = sum + self.get(Vec2(i, k)) * rhs.get(Vec2(k, j));
-                }
-                buffer.push(sum);
-            }
-        }
-        Matrix::new(Vec2(x, y2), buffer)
+        &self * &rhs
     }
 }
 
-impl <'a, 'b, T> Mul<&'b Matrix<T>> for &'a Matrix<T> where T: Mul<Output=T> + Add<Output=T> + Copy {
+impl <T> Mul<&Matrix<T>> for &Matrix<T> where T: Mul<Output=T> + Add<Output=T> + Copy + Default + Send + Sync {
     type Output = Matrix<T>;
 
     /// Multiply Matricies by reference
@@ -388,25 +872,16 @@ impl <'a, 'b, T> Mul<&'b Matrix<T>> for &'a Matrix<T> where T: Mul<Output=T> + A
     /// assert_eq!(matrix2.get(ktensor::math::Vec2(0, 0)), 5.0);
     /// assert_eq!(matrix3.get(ktensor::math::Vec2(0, 0)), 5.0);
     /// ```
-    fn mul(self, rhs: &'b Matrix<T>) -> Matrix<T> {
+    fn mul(self, rhs: &Matrix<T>) -> Matrix<T> {
         let Vec2(x, y) = self.dim;
         let Vec2(x2, y2) = rhs.dim;
         assert_eq!(y, x2);
-        let mut buffer = Vec::with_capacity(x * y2);
-        for i in 0..x {
-            for j in 0..y2 {
-                let mut sum = self.get(Vec2(i, 0)) * rhs.get(Vec2(0, j));
-                for k in 1..y {
-                    sum = sum + self.get(Vec2(i, k)) * rhs.get(Vec2(k, j));
-                }
-                buffer.push(sum);
-            }
-        }
+        let buffer = gemm::multiply(&self.operand(), &rhs.operand(), self.dim, y2);
         Matrix::new(Vec2(x, y2), buffer)
     }
 }
 
-impl <T> Mul<T> for Matrix<T> where T: Mul<Output=T> + Copy {
+impl <T> Mul<T> for Matrix<T> where T: Mul<Output=T> + Copy + Send + Sync {
     type Output = Matrix<T>;
 
     /// Multiply Matrix and a constant
@@ -425,15 +900,11 @@ impl <T> Mul<T> for Matrix<T> where T: Mul<Output=T> + Copy {
     /// assert_eq!(matrix2.get(ktensor::math::Vec2(0, 2)), 4.0);
     /// ```
     fn mul(self, rhs: T) -> Matrix<T> {
-        let mut buffer = Vec::with_capacity(self.len());
-        for &i in self.buffer.iter() {
-            buffer.push(i * rhs);
-        }
-        Matrix::new(self.dim, buffer)
+        &self * &rhs
     }
 }
 
-impl <'a, 'b, T> Mul<&'b T> for &'a Matrix<T> where T: Mul<Output=T> + Copy {
+impl <T> Mul<&T> for &Matrix<T> where T: Mul<Output=T> + Copy + Send + Sync {
     type Output = Matrix<T>;
 
     /// Multiply Matrix and a constant
@@ -453,12 +924,8 @@ impl <'a, 'b, T> Mul<&'b T> for &'a Matrix<T> where T: Mul<Output=T> + Copy {
     /// assert_eq!(matrix2.get(ktensor::math::Vec2(0, 2)), 4.0);
     /// assert_eq!(float, 2.0);
     /// ```
-    fn mul(self, &rhs: &'b T) -> Matrix<T> {
-        let mut buffer = Vec::with_capacity(self.len());
-        for &i in self.buffer.iter() {
-            buffer.push(i * rhs);
-        }
-        Matrix::new(self.dim, buffer)
+    fn mul(self, &rhs: &T) -> Matrix<T> {
+        self.map(|a| a * rhs)
     }
 }
 
@@ -467,8 +934,8 @@ impl <'a, 'b, T> Mul<&'b T> for &'a Matrix<T> where T: Mul<Output=T> + Copy {
 // Hadamard Product  //
 ///////////////////////
 
-impl <'a, T> Matrix<T> where T: Mul<Output=T> + Copy {
-    /// Hadamard Product of Matricies by reference
+impl <T> Matrix<T> where T: Mul<Output=T> + Copy + Send + Sync {
+    /// Hadamard Product of Matricies by reference, broadcasting axes of length 1
     ///
     /// # Arguments
     ///
@@ -485,15 +952,368 @@ impl <'a, T> Matrix<T> where T: Mul<Output=T> + Copy {
     /// assert_eq!(matrix2.get(ktensor::math::Vec2(0, 2)), 3.0);
     /// assert_eq!(matrix3.get(ktensor::math::Vec2(0, 2)), 6.0);
     /// ```
-    pub fn product(&self, rhs: &'a Matrix<T>) -> Matrix<T> {
-        assert_eq!(self.dim.0, rhs.dim.0);
-        assert_eq!(self.dim.1, rhs.dim.1);
-        let mut buffer = Vec::with_capacity(self.len());
-        for i in 0..self.dim.0 {
-            for j in 0..self.dim.1 {
-                buffer.push(self.get(Vec2(i, j)) * rhs.get(Vec2(i, j)));
-            }
+    pub fn product(&self, rhs: &Matrix<T>) -> Matrix<T> {
+        self.zip_map(rhs, |a, b| a * b)
+    }
+}
+
+
+///////////////
+// Division  //
+///////////////
+
+impl <T> Div<Matrix<T>> for Matrix<T> where T: Div<Output=T> + Copy + Send + Sync {
+    type Output = Matrix<T>;
+
+    /// Divide Matricies elementwise, broadcasting axes of length 1
+    ///
+    /// # Arguments
+    ///
+    /// - `self` - this matrix
+    /// - `rhs` - another matrix
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let matrix1 = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), (0..6).map(|i| i as f64).collect());
+    /// let matrix2 = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), (1..7).map(|i| i as f64).rev().collect());
+    /// let matrix3 = matrix1 / matrix2;
+    /// assert_eq!(matrix3.get(ktensor::math::Vec2(0, 2)), 0.5);
+    /// ```
+    fn div(self, rhs: Matrix<T>) -> Matrix<T> {
+        &self / &rhs
+    }
+}
+
+impl <T> Div<&Matrix<T>> for &Matrix<T> where T: Div<Output=T> + Copy + Send + Sync {
+    type Output = Matrix<T>;
+
+    /// Divide Matricies elementwise by reference, broadcasting axes of length 1
+    ///
+    /// # Arguments
+    ///
+    /// - `self` - this matrix reference
+    /// - `rhs` - another matrix reference
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let matrix1 = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), (0..6).map(|i| i as f64).collect());
+    /// let matrix2 = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), (1..7).map(|i| i as f64).rev().collect());
+    /// let matrix3 = &matrix1 / &matrix2;
+    /// assert_eq!(matrix1.get(ktensor::math::Vec2(0, 2)), 2.0);
+    /// assert_eq!(matrix2.get(ktensor::math::Vec2(0, 2)), 4.0);
+    /// assert_eq!(matrix3.get(ktensor::math::Vec2(0, 2)), 0.5);
+    /// ```
+    fn div(self, rhs: &Matrix<T>) -> Matrix<T> {
+        self.zip_map(rhs, |a, b| a / b)
+    }
+}
+
+impl <T> Div<T> for Matrix<T> where T: Div<Output=T> + Copy + Send + Sync {
+    type Output = Matrix<T>;
+
+    /// Divide Matrix and a constant
+    ///
+    /// # Arguments
+    ///
+    /// - `self` - this matrix
+    /// - `rhs` - a constant
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let matrix1 = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), (0..6).map(|i| i as f64).collect());
+    /// let float = 2.0;
+    /// let matrix2 = matrix1 / float;
+    /// assert_eq!(matrix2.get(ktensor::math::Vec2(0, 2)), 1.0);
+    /// ```
+    fn div(self, rhs: T) -> Matrix<T> {
+        &self / &rhs
+    }
+}
+
+impl <T> Div<&T> for &Matrix<T> where T: Div<Output=T> + Copy + Send + Sync {
+    type Output = Matrix<T>;
+
+    /// Divide Matrix and a constant
+    ///
+    /// # Arguments
+    ///
+    /// - `self` - this matrix reference
+    /// - `rhs` - a constant reference
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let matrix1 = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), (0..6).map(|i| i as f64).collect());
+    /// let float = 2.0;
+    /// let matrix2 = &matrix1 / &float;
+    /// assert_eq!(matrix1.get(ktensor::math::Vec2(0, 2)), 2.0);
+    /// assert_eq!(matrix2.get(ktensor::math::Vec2(0, 2)), 1.0);
+    /// assert_eq!(float, 2.0);
+    /// ```
+    fn div(self, &rhs: &T) -> Matrix<T> {
+        self.map(|a| a / rhs)
+    }
+}
+
+
+///////////////
+// Negation  //
+///////////////
+
+impl <T> Neg for Matrix<T> where T: Neg<Output=T> + Copy + Send + Sync {
+    type Output = Matrix<T>;
+
+    /// Negate Matrix
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let matrix1 = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), (0..6).map(|i| i as f64).collect());
+    /// let matrix2 = -matrix1;
+    /// assert_eq!(matrix2.get(ktensor::math::Vec2(0, 2)), -2.0);
+    /// ```
+    fn neg(self) -> Matrix<T> {
+        -&self
+    }
+}
+
+impl <T> Neg for &Matrix<T> where T: Neg<Output=T> + Copy + Send + Sync {
+    type Output = Matrix<T>;
+
+    /// Negate Matrix by reference
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let matrix1 = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), (0..6).map(|i| i as f64).collect());
+    /// let matrix2 = -&matrix1;
+    /// assert_eq!(matrix1.get(ktensor::math::Vec2(0, 2)), 2.0);
+    /// assert_eq!(matrix2.get(ktensor::math::Vec2(0, 2)), -2.0);
+    /// ```
+    fn neg(self) -> Matrix<T> {
+        self.map(|a| -a)
+    }
+}
+
+
+/////////////////
+// Assignment  //
+/////////////////
+
+impl <T> AddAssign<Matrix<T>> for Matrix<T> where T: Add<Output=T> + Copy + Send + Sync {
+    /// Add a Matrix in place, broadcasting `rhs` to the dimensions of `self`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut matrix1 = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), (0..6).map(|i| i as f64).collect());
+    /// let matrix2 = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), (1..7).map(|i| i as f64).rev().collect());
+    /// matrix1 += matrix2;
+    /// assert_eq!(matrix1.get(ktensor::math::Vec2(0, 2)), 6.0);
+    /// ```
+    fn add_assign(&mut self, rhs: Matrix<T>) {
+        self.update_items(&rhs, |a, b| a + b);
+    }
+}
+
+impl <T> AddAssign<&Matrix<T>> for Matrix<T> where T: Add<Output=T> + Copy + Send + Sync {
+    /// Add a Matrix reference in place, broadcasting `rhs` to the dimensions of `self`
+    ///
+    /// The buffer is reused unless it is shared with another `Matrix`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut matrix1 = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), (0..6).map(|i| i as f64).collect());
+    /// let bias = ktensor::math::Matrix::new(ktensor::math::Vec2(1, 3), vec![1.0, 1.0, 1.0]);
+    /// matrix1 += &bias;
+    /// assert_eq!(bias.get(ktensor::math::Vec2(0, 2)), 1.0);
+    /// assert_eq!(matrix1.get(ktensor::math::Vec2(1, 2)), 5.0 + 1.0);
+    /// ```
+    fn add_assign(&mut self, rhs: &Matrix<T>) {
+        self.update_items(rhs, |a, b| a + b);
+    }
+}
+
+impl <T> AddAssign<T> for Matrix<T> where T: Add<Output=T> + Copy + Send + Sync {
+    /// Add a constant in place
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut matrix1 = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), (0..6).map(|i| i as f64).collect());
+    /// matrix1 += 2.0;
+    /// assert_eq!(matrix1.get(ktensor::math::Vec2(0, 2)), 4.0);
+    /// ```
+    fn add_assign(&mut self, rhs: T) {
+        self.update_map(|a| a + rhs);
+    }
+}
+
+impl <T> SubAssign<Matrix<T>> for Matrix<T> where T: Sub<Output=T> + Copy + Send + Sync {
+    /// Subtract a Matrix in place, broadcasting `rhs` to the dimensions of `self`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut matrix1 = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), (0..6).map(|i| i as f64).collect());
+    /// let matrix2 = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), (1..7).map(|i| i as f64).rev().collect());
+    /// matrix1 -= matrix2;
+    /// assert_eq!(matrix1.get(ktensor::math::Vec2(0, 2)), -2.0);
+    /// ```
+    fn sub_assign(&mut self, rhs: Matrix<T>) {
+        self.update_items(&rhs, |a, b| a - b);
+    }
+}
+
+impl <T> SubAssign<&Matrix<T>> for Matrix<T> where T: Sub<Output=T> + Copy + Send + Sync {
+    /// Subtract a Matrix reference in place, broadcasting `rhs` to the dimensions of `self`
+    ///
+    /// The buffer is reused unless it is shared with another `Matrix`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut matrix1 = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), (0..6).map(|i| i as f64).collect());
+    /// let bias = ktensor::math::Matrix::new(ktensor::math::Vec2(1, 3), vec![1.0, 1.0, 1.0]);
+    /// matrix1 -= &bias;
+    /// assert_eq!(bias.get(ktensor::math::Vec2(0, 2)), 1.0);
+    /// assert_eq!(matrix1.get(ktensor::math::Vec2(1, 2)), 5.0 - 1.0);
+    /// ```
+    fn sub_assign(&mut self, rhs: &Matrix<T>) {
+        self.update_items(rhs, |a, b| a - b);
+    }
+}
+
+impl <T> SubAssign<T> for Matrix<T> where T: Sub<Output=T> + Copy + Send + Sync {
+    /// Subtract a constant in place
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut matrix1 = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), (0..6).map(|i| i as f64).collect());
+    /// matrix1 -= 2.0;
+    /// assert_eq!(matrix1.get(ktensor::math::Vec2(0, 2)), 0.0);
+    /// ```
+    fn sub_assign(&mut self, rhs: T) {
+        self.update_map(|a| a - rhs);
+    }
+}
+
+impl <T> MulAssign<T> for Matrix<T> where T: Mul<Output=T> + Copy + Send + Sync {
+    /// Multiply by a constant in place
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut matrix1 = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), (0..6).map(|i| i as f64).collect());
+    /// matrix1 *= 2.0;
+    /// assert_eq!(matrix1.get(ktensor::math::Vec2(0, 2)), 4.0);
+    /// ```
+    fn mul_assign(&mut self, rhs: T) {
+        self.update_map(|a| a * rhs);
+    }
+}
+
+/////////////////////////
+// Checked Arithmetic  //
+/////////////////////////
+
+impl <T> Matrix<T> where T: Add<Output=T> + Copy + Send + Sync {
+    /// Add Matricies by reference, or return a `ShapeMismatch` if they cannot be broadcast
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let matrix1: ktensor::math::Matrix<i32> = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), (0..6).collect());
+    /// let matrix2: ktensor::math::Matrix<i32> = ktensor::math::Matrix::new(ktensor::math::Vec2(3, 2), (0..6).collect());
+    /// assert!(matrix1.try_add(&matrix1).is_ok());
+    /// assert!(matrix1.try_add(&matrix2).is_err());
+    /// ```
+    pub fn try_add(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, KTensorError> {
+        self.try_broadcast(rhs)?;
+        Ok(self + rhs)
+    }
+}
+
+impl <T> Matrix<T> where T: Sub<Output=T> + Copy + Send + Sync {
+    /// Subtract Matricies by reference, or return a `ShapeMismatch` if they cannot be broadcast
+    pub fn try_sub(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, KTensorError> {
+        self.try_broadcast(rhs)?;
+        Ok(self - rhs)
+    }
+}
+
+impl <T> Matrix<T> where T: Mul<Output=T> + Add<Output=T> + Copy + Default + Send + Sync {
+    /// Multiply Matricies by reference, or return a `ShapeMismatch` if the shared dimensions differ
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let matrix1: ktensor::math::Matrix<i32> = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), (0..6).collect());
+    /// let matrix2: ktensor::math::Matrix<i32> = ktensor::math::Matrix::new(ktensor::math::Vec2(3, 2), (0..6).collect());
+    /// assert!(matrix1.try_mul(&matrix2).is_ok());
+    /// assert!(matrix1.try_mul(&matrix1).is_err());
+    /// ```
+    pub fn try_mul(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, KTensorError> {
+        if self.dim.1 != rhs.dim.0 {
+            return Err(KTensorError::shape_mismatch(self.dim, rhs.dim));
         }
-        Matrix::new(self.dim, buffer)
+        Ok(self * rhs)
+    }
+}
+
+impl <T> Matrix<T> where T: Mul<Output=T> + Copy + Send + Sync {
+    /// Hadamard Product of Matricies by reference, or return a `ShapeMismatch` if they cannot be broadcast
+    pub fn try_product(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, KTensorError> {
+        self.try_broadcast(rhs)?;
+        Ok(self.product(rhs))
+    }
+}
+
+impl <T> Matrix<T> where T: Div<Output=T> + Copy + Send + Sync {
+    /// Divide Matricies elementwise by reference, or return a `ShapeMismatch` if they cannot be broadcast
+    pub fn try_div(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, KTensorError> {
+        self.try_broadcast(rhs)?;
+        Ok(self / rhs)
+    }
+}
+
+/////////////////
+// Comparison  //
+/////////////////
+
+impl <T> PartialEq for Matrix<T> where T: PartialEq + Copy {
+    /// Matricies are equal if they have the same dimensions and items, regardless of layout
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let matrix = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), (0..6).collect::<Vec<i32>>());
+    /// let transposed = ktensor::math::Matrix::new(ktensor::math::Vec2(3, 2), vec![0, 3, 1, 4, 2, 5]);
+    /// assert!(matrix.transpose() == transposed);
+    /// assert!(matrix != transposed);
+    /// ```
+    fn eq(&self, other: &Matrix<T>) -> bool {
+        self.dim == other.dim && self.iter().eq(other.iter())
+    }
+}
+
+impl <T> Matrix<T> where T: Float {
+    /// Returns `true` if the matricies have the same dimensions and every pair of items differ by at most `tolerance`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let matrix1 = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 2), vec![1.0, 2.0, 3.0, 4.0]);
+    /// let matrix2 = &matrix1 + &1e-9;
+    /// assert!(matrix1.approx_eq(&matrix2, 1e-6));
+    /// assert!(!matrix1.approx_eq(&matrix2, 1e-12));
+    /// ```
+    pub fn approx_eq(&self, other: &Matrix<T>, tolerance: T) -> bool {
+        self.dim == other.dim && self.iter().zip(other.iter()).all(|(a, b)| (a - b).abs() <= tolerance)
     }
 }
@@ -1,2 +1,19 @@
 mod matrix;
+mod shape;
+mod float;
+mod cast;
+mod gemm;
+mod parallel;
+mod linalg;
+mod decomposition;
+mod sparse;
+mod format;
 pub use self::matrix::{Matrix, Vec2};
+pub use self::sparse::{SparseMatrix};
+pub use self::shape::{Shape};
+pub use self::float::{Float};
+pub use self::cast::{Cast};
+pub use self::parallel::{set_threads, threads};
+pub use self::linalg::{Lu};
+pub use self::decomposition::{Qr, Svd, SymmetricEigen};
+pub(crate) use self::format::{write_grid};
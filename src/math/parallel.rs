@@ -0,0 +1,241 @@
+use std::any::{Any};
+use std::cell::{Cell};
+use std::cmp;
+use std::mem;
+use std::ops::{Range};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Condvar, Mutex, OnceLock};
+use std::thread;
+
+/// number of workers used by `Matrix` and `Tensor` kernels
+static THREADS: AtomicUsize = AtomicUsize::new(1);
+
+/// minimum amount of work handed to a single worker
+///
+/// `benches/parallel.rs` measures an elementwise kernel at about 220 items per µs on one core, so 2^15 items
+/// take about 150µs. Splitting 2^16 items across 2 pooled workers costs about 50µs more than running them
+/// serially when both share a single core, against about 85µs when spawning scoped threads per call,
+/// so a worker always gets several times more work than it costs to hand it over.
+const MIN_WORK: usize = 1 << 15;
+
+/// job run by a pooled worker
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+/// Persistent workers shared by every kernel, started on first use
+struct Pool {
+    /// queue of jobs, taken by whichever worker is idle
+    sender: Mutex<Sender<Job>>,
+    receiver: Arc<Mutex<Receiver<Job>>>,
+    /// number of workers started
+    size: Mutex<usize>,
+}
+
+thread_local! {
+    /// set on pooled workers, so kernels called from a job run serially instead of waiting on the pool
+    static IS_WORKER: Cell<bool> = const { Cell::new(false) };
+}
+
+impl Pool {
+    fn get() -> &'static Pool {
+        static POOL: OnceLock<Pool> = OnceLock::new();
+        POOL.get_or_init(|| {
+            let (sender, receiver) = mpsc::channel();
+            Pool {
+                sender: Mutex::new(sender),
+                receiver: Arc::new(Mutex::new(receiver)),
+                size: Mutex::new(0),
+            }
+        })
+    }
+
+    /// Starts workers until there are at least `count`
+    fn reserve(&self, count: usize) {
+        let mut size = self.size.lock().unwrap();
+        while *size < count {
+            let receiver = self.receiver.clone();
+            thread::Builder::new().name(format!("ktensor-worker-{}", *size)).spawn(move || {
+                IS_WORKER.with(|is_worker| is_worker.set(true));
+                loop {
+                    let job = receiver.lock().unwrap().recv();
+                    match job {
+                        Ok(job) => job(),
+                        Err(_)  => return,
+                    }
+                }
+            }).expect("failed to start a ktensor worker thread");
+            *size += 1;
+        }
+    }
+
+    /// Runs the first task on the calling thread and the rest on the workers, returning once every task has finished
+    ///
+    /// A panic in any task is resumed on the calling thread after all tasks have finished.
+    fn run<'a>(&self, mut tasks: Vec<Box<dyn FnOnce() + Send + 'a>>) {
+        if tasks.is_empty() {
+            return;
+        }
+        let first = tasks.remove(0);
+        self.reserve(tasks.len());
+
+        let latch = Arc::new(Latch::new(tasks.len()));
+        {
+            // waits for the workers even if the task on the calling thread panics
+            let _guard = WaitGuard(&latch);
+            let sender = self.sender.lock().unwrap().clone();
+            for task in tasks {
+                let latch = latch.clone();
+                let job: Box<dyn FnOnce() + Send + 'a> = Box::new(move || {
+                    let result = panic::catch_unwind(AssertUnwindSafe(task));
+                    latch.finish(result.err());
+                });
+                // SAFETY: the job only borrows data outliving `'a`, and `_guard` blocks until every job has
+                // finished before this function returns or unwinds, so no borrow outlives its data
+                let job: Job = unsafe { mem::transmute::<Box<dyn FnOnce() + Send + 'a>, Job>(job) };
+                sender.send(job).expect("ktensor workers have stopped");
+            }
+            first();
+        }
+        let panic = latch.panic.lock().unwrap().take();
+        if let Some(reason) = panic {
+            panic::resume_unwind(reason);
+        }
+    }
+}
+
+/// Counts the jobs of a `Pool::run` still running
+struct Latch {
+    remaining: Mutex<usize>,
+    finished: Condvar,
+    /// first panic raised by a job
+    panic: Mutex<Option<Box<dyn Any + Send>>>,
+}
+
+impl Latch {
+    fn new(count: usize) -> Latch {
+        Latch {
+            remaining: Mutex::new(count),
+            finished: Condvar::new(),
+            panic: Mutex::new(None),
+        }
+    }
+
+    fn finish(&self, panic: Option<Box<dyn Any + Send>>) {
+        if let Some(reason) = panic {
+            self.panic.lock().unwrap().get_or_insert(reason);
+        }
+        let mut remaining = self.remaining.lock().unwrap();
+        *remaining -= 1;
+        if *remaining == 0 {
+            self.finished.notify_all();
+        }
+    }
+
+    fn wait(&self) {
+        let mut remaining = self.remaining.lock().unwrap();
+        while *remaining > 0 {
+            remaining = self.finished.wait(remaining).unwrap();
+        }
+    }
+}
+
+/// Waits on a `Latch` when dropped
+struct WaitGuard<'a>(&'a Latch);
+
+impl <'a> Drop for WaitGuard<'a> {
+    fn drop(&mut self) {
+        self.0.wait();
+    }
+}
+
+/// Returns the number of workers to split `work` between, given at most `parts` parts
+fn workers(work: usize, parts: usize) -> usize {
+    if IS_WORKER.with(|is_worker| is_worker.get()) {
+        return 1;
+    }
+    cmp::min(cmp::min(threads(), work / MIN_WORK), parts)
+}
+
+/// Sets the number of worker threads used by `Matrix` and `Tensor` arithmetic
+///
+/// Kernels run on the calling thread by default. Workers are started the first time they are needed and
+/// are kept for the life of the process, so later kernels do not pay for spawning threads.
+/// Results do not depend on the number of threads.
+///
+/// # Arguments
+///
+/// - `count` - number of workers, values below 1 are treated as 1
+///
+/// # Example
+///
+/// ```
+/// use ktensor::math::{Matrix, Vec2};
+/// let matrix1 = Matrix::new(Vec2(128, 256), (0..128 * 256).map(|i| (i % 7) as f64 / 7.0).collect());
+/// let matrix2 = Matrix::new(Vec2(256, 64), (0..256 * 64).map(|i| (i % 5) as f64 / 5.0).collect());
+/// let single = (&matrix1 * &matrix2).to_flattened();
+/// ktensor::math::set_threads(4);
+/// assert_eq!(ktensor::math::threads(), 4);
+/// let multi = (&matrix1 * &matrix2).to_flattened();
+/// assert!(single == multi);
+/// ```
+pub fn set_threads(count: usize) {
+    THREADS.store(cmp::max(count, 1), Ordering::Relaxed);
+}
+
+/// Returns the number of worker threads used by `Matrix` and `Tensor` arithmetic
+pub fn threads() -> usize {
+    THREADS.load(Ordering::Relaxed)
+}
+
+/// Builds a buffer of `len` items by splitting `0..len` into contiguous ranges computed across workers
+///
+/// # Arguments
+///
+/// - `len` - number of items to split between workers
+/// - `cost` - amount of work needed for a single item
+/// - `f` - returns the items of a range in order
+pub fn collect<T, F>(len: usize, cost: usize, f: F) -> Vec<T> where T: Send, F: Fn(Range<usize>) -> Vec<T> + Sync {
+    let workers = workers(len.saturating_mul(cmp::max(cost, 1)), len);
+    if workers <= 1 {
+        return f(0..len);
+    }
+
+    let chunk = len.div_ceil(workers);
+    let f = &f;
+    let mut parts: Vec<Vec<T>> = (0..len).step_by(chunk).map(|_| Vec::new()).collect();
+    Pool::get().run(parts.iter_mut().enumerate().map(|(i, part)| {
+        let start = i * chunk;
+        Box::new(move || *part = f(start..cmp::min(start + chunk, len))) as Box<dyn FnOnce() + Send>
+    }).collect());
+    let mut buffer = Vec::with_capacity(len);
+    for part in parts {
+        buffer.extend(part);
+    }
+    buffer
+}
+
+/// Updates `buffer` in place by splitting it into contiguous runs of rows across workers
+///
+/// # Arguments
+///
+/// - `buffer` - row-major items to update
+/// - `cols` - number of items in a row
+/// - `f` - updates a run of rows, given the index of its first row
+pub fn update<T, F>(buffer: &mut [T], cols: usize, f: F) where T: Send, F: Fn(usize, &mut [T]) + Sync {
+    if cols == 0 || buffer.is_empty() {
+        return;
+    }
+    let rows = buffer.len() / cols;
+    let workers = workers(buffer.len(), rows);
+    if workers <= 1 {
+        f(0, buffer);
+        return;
+    }
+
+    let chunk = rows.div_ceil(workers);
+    let f = &f;
+    Pool::get().run(buffer.chunks_mut(chunk * cols).enumerate().map(|(i, run)| {
+        Box::new(move || f(i * chunk, run)) as Box<dyn FnOnce() + Send>
+    }).collect());
+}
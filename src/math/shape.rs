@@ -0,0 +1,112 @@
+use math::{Vec2};
+
+/// Dimensions of a `Tensor` of any rank
+///
+/// Items are laid out in row-major order, so the last axis is contiguous.
+/// A `Shape` is viewed as a `Matrix` of `(product of the leading axes, last axis)`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Shape {
+    /// length of each axis
+    dims: Vec<usize>,
+}
+
+impl Shape {
+    /// Returns a new `Shape`
+    ///
+    /// # Arguments
+    ///
+    /// - `dimensions` - length of each axis
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let shape = ktensor::math::Shape::new(vec![2, 3, 4]);
+    /// assert_eq!(shape.rank(), 3);
+    /// assert_eq!(shape.len(), 24);
+    /// ```
+    pub fn new(dimensions: Vec<usize>) -> Shape {
+        Shape {
+            dims: dimensions,
+        }
+    }
+
+    /// Returns the length of each axis
+    pub fn dims(&self) -> &[usize] {
+        &self.dims
+    }
+
+    /// Returns the number of axes
+    pub fn rank(&self) -> usize {
+        self.dims.len()
+    }
+
+    /// Returns the total number of items
+    pub fn len(&self) -> usize {
+        self.dims.iter().product()
+    }
+
+    /// Returns `true` if any axis has a length of 0
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of items skipped by a step along each axis
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let shape = ktensor::math::Shape::new(vec![2, 3, 4]);
+    /// assert_eq!(shape.strides(), vec![12, 4, 1]);
+    /// ```
+    pub fn strides(&self) -> Vec<usize> {
+        let mut strides = vec![1; self.dims.len()];
+        for i in (1..self.dims.len()).rev() {
+            strides[i - 1] = strides[i] * self.dims[i];
+        }
+        strides
+    }
+
+    /// Returns the row-major offset of `coords`
+    ///
+    /// # Arguments
+    ///
+    /// - `coords` - one coordinate per axis
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let shape = ktensor::math::Shape::new(vec![2, 3, 4]);
+    /// assert_eq!(shape.offset(&[1, 2, 3]), 23);
+    /// ```
+    pub fn offset(&self, coords: &[usize]) -> usize {
+        assert_eq!(coords.len(), self.dims.len());
+        coords.iter().zip(self.dims.iter()).zip(self.strides().iter()).fold(0, |sum, ((&i, &dim), &stride)| {
+            assert!(i < dim);
+            sum + i * stride
+        })
+    }
+
+    /// Returns the dimensions of the `Matrix` view of the `Shape`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let shape = ktensor::math::Shape::new(vec![2, 3, 4]);
+    /// let ktensor::math::Vec2(row, col) = shape.to_vec2();
+    /// assert_eq!((row, col), (6, 4));
+    /// let ktensor::math::Vec2(row, col) = ktensor::math::Shape::new(vec![5]).to_vec2();
+    /// assert_eq!((row, col), (1, 5));
+    /// ```
+    pub fn to_vec2(&self) -> Vec2 {
+        match self.dims.split_last() {
+            Some((&col, rows)) => Vec2(rows.iter().product(), col),
+            None               => Vec2(1, 1),
+        }
+    }
+}
+
+impl From<Vec2> for Shape {
+    fn from(Vec2(row, col): Vec2) -> Shape {
+        Shape::new(vec![row, col])
+    }
+}
@@ -0,0 +1,329 @@
+use std::ops::{Mul};
+use math::{Matrix, Vec2, Float};
+use math::parallel;
+
+/// A `Matrix` of mostly zeros in compressed sparse row (CSR) form
+///
+/// The nonzero items of row `i` are `values[row_offsets[i]..row_offsets[i + 1]]`,
+/// in the columns `col_indices[row_offsets[i]..row_offsets[i + 1]]`.
+#[derive(Clone)]
+pub struct SparseMatrix<T> {
+    /// vector of rows and columns
+    dim: Vec2,
+    /// start of each row in `col_indices` and `values`, followed by the number of stored items
+    row_offsets: Vec<usize>,
+    /// column of each stored item, ascending within a row
+    col_indices: Vec<usize>,
+    /// stored items
+    values: Vec<T>,
+}
+
+impl <T> SparseMatrix<T> {
+    /// Returns a new `SparseMatrix` from its CSR arrays
+    ///
+    /// # Arguments
+    ///
+    /// - `dimensions` - dimensions of `SparseMatrix`
+    /// - `row_offsets` - start of each row, followed by the number of stored items
+    /// - `col_indices` - column of each stored item, ascending within a row
+    /// - `values` - stored items
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let sparse = ktensor::math::SparseMatrix::new(ktensor::math::Vec2(2, 3), vec![0, 1, 3], vec![2, 0, 1], vec![1.0, 2.0, 3.0]);
+    /// assert_eq!(sparse.nnz(), 3);
+    /// assert_eq!(sparse.to_dense().to_flattened(), vec![0.0, 0.0, 1.0, 2.0, 3.0, 0.0]);
+    /// ```
+    ///
+    /// Row offsets must not decrease and must end at the number of stored items:
+    ///
+    /// ```should_panic
+    /// ktensor::math::SparseMatrix::new(ktensor::math::Vec2(2, 3), vec![0, 2, 1], vec![0, 1], vec![1.0, 2.0]);
+    /// ```
+    pub fn new(dimensions: Vec2, row_offsets: Vec<usize>, col_indices: Vec<usize>, values: Vec<T>) -> SparseMatrix<T> {
+        let Vec2(row, col) = dimensions;
+        assert_eq!(row_offsets.len(), row + 1, "expected {} row offsets", row + 1);
+        assert_eq!(row_offsets[0], 0, "row offsets must start at 0");
+        assert!(row_offsets.windows(2).all(|pair| pair[0] <= pair[1]), "row offsets must be ascending");
+        assert_eq!(row_offsets[row], values.len(), "last row offset must be the number of stored items");
+        assert_eq!(col_indices.len(), values.len(), "expected a column for each stored item");
+        for i in 0..row {
+            let cols = &col_indices[row_offsets[i]..row_offsets[i + 1]];
+            assert!(cols.windows(2).all(|pair| pair[0] < pair[1]), "columns of row {} must be ascending", i);
+            assert!(cols.iter().all(|&j| j < col), "column out of bounds in row {}", i);
+        }
+        SparseMatrix {
+            dim: dimensions,
+            row_offsets,
+            col_indices,
+            values,
+        }
+    }
+
+    /// Returns the dimensions of the `SparseMatrix`
+    pub fn dim(&self) -> Vec2 {
+        self.dim
+    }
+
+    /// Returns the number of stored items
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns the start of each row, followed by the number of stored items
+    pub fn row_offsets(&self) -> &[usize] {
+        &self.row_offsets
+    }
+
+    /// Returns the column of each stored item
+    pub fn col_indices(&self) -> &[usize] {
+        &self.col_indices
+    }
+
+    /// Returns the stored items
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    /// Returns an iterator over the columns and items stored in row `i`
+    pub fn row(&self, i: usize) -> impl Iterator<Item=(usize, &T)> + '_ {
+        let range = self.row_offsets[i]..self.row_offsets[i + 1];
+        self.col_indices[range.clone()].iter().cloned().zip(self.values[range].iter())
+    }
+}
+
+impl <T> SparseMatrix<T> where T: Float {
+    /// Returns a `SparseMatrix` of the nonzero items of a dense `Matrix`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let dense = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), vec![0.0, 4.0, 0.0, 0.0, 0.0, 5.0]);
+    /// let sparse = ktensor::math::SparseMatrix::from_dense(&dense);
+    /// assert_eq!(sparse.row_offsets(), &[0, 1, 2]);
+    /// assert_eq!(sparse.col_indices(), &[1, 2]);
+    /// assert_eq!(sparse.values(), &[4.0, 5.0]);
+    /// ```
+    pub fn from_dense(matrix: &Matrix<T>) -> SparseMatrix<T> {
+        let mut row_offsets = Vec::with_capacity(matrix.dim().0 + 1);
+        let mut col_indices = Vec::new();
+        let mut values = Vec::new();
+        row_offsets.push(0);
+        for row in matrix.rows() {
+            for (j, value) in row.iter().enumerate() {
+                if value != T::zero() {
+                    col_indices.push(j);
+                    values.push(value);
+                }
+            }
+            row_offsets.push(values.len());
+        }
+        SparseMatrix {
+            dim: matrix.dim(),
+            row_offsets,
+            col_indices,
+            values,
+        }
+    }
+
+    /// Returns a `SparseMatrix` from `(row, column, value)` triplets in any order, summing duplicates
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let sparse = ktensor::math::SparseMatrix::from_triplets(ktensor::math::Vec2(2, 2), vec![(1, 0, 1.0), (0, 1, 2.0), (1, 0, 3.0)]);
+    /// assert_eq!(sparse.to_dense().to_flattened(), vec![0.0, 2.0, 4.0, 0.0]);
+    /// ```
+    pub fn from_triplets(dimensions: Vec2, mut triplets: Vec<(usize, usize, T)>) -> SparseMatrix<T> {
+        let Vec2(row, col) = dimensions;
+        triplets.sort_by_key(|&(i, j, _)| (i, j));
+        let mut row_offsets = vec![0; row + 1];
+        let mut col_indices: Vec<usize> = Vec::with_capacity(triplets.len());
+        let mut values: Vec<T> = Vec::with_capacity(triplets.len());
+        let mut last = None;
+        for (i, j, value) in triplets {
+            assert!(i < row && j < col, "triplet ({}, {}) is out of bounds", i, j);
+            if last == Some((i, j)) {
+                let sum = values.pop().unwrap() + value;
+                values.push(sum);
+            } else {
+                col_indices.push(j);
+                values.push(value);
+                row_offsets[i + 1] += 1;
+                last = Some((i, j));
+            }
+        }
+        for i in 0..row {
+            row_offsets[i + 1] += row_offsets[i];
+        }
+        SparseMatrix {
+            dim: dimensions,
+            row_offsets,
+            col_indices,
+            values,
+        }
+    }
+
+    /// Returns value at `Vec2(row, column)`
+    pub fn get(&self, Vec2(x, y): Vec2) -> T {
+        let range = self.row_offsets[x]..self.row_offsets[x + 1];
+        match self.col_indices[range.clone()].binary_search(&y) {
+            Ok(k)  => self.values[range.start + k],
+            Err(_) => T::zero(),
+        }
+    }
+
+    /// Returns the dense `Matrix` of the `SparseMatrix`
+    pub fn to_dense(&self) -> Matrix<T> {
+        let Vec2(row, col) = self.dim;
+        let mut buffer = vec![T::zero(); row * col];
+        for i in 0..row {
+            for (j, &value) in self.row(i) {
+                buffer[i * col + j] = value;
+            }
+        }
+        Matrix::new(self.dim, buffer)
+    }
+
+    /// Returns the transposed `SparseMatrix`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let sparse = ktensor::math::SparseMatrix::new(ktensor::math::Vec2(2, 3), vec![0, 1, 3], vec![2, 0, 1], vec![1.0, 2.0, 3.0]);
+    /// let dense = sparse.to_dense().transpose();
+    /// assert_eq!(sparse.transpose().to_dense().to_flattened(), dense.to_flattened());
+    /// ```
+    pub fn transpose(&self) -> SparseMatrix<T> {
+        let Vec2(row, col) = self.dim;
+        let mut row_offsets = vec![0; col + 1];
+        for &j in &self.col_indices {
+            row_offsets[j + 1] += 1;
+        }
+        for j in 0..col {
+            row_offsets[j + 1] += row_offsets[j];
+        }
+        let mut next = row_offsets.clone();
+        let mut col_indices = vec![0; self.nnz()];
+        let mut values = vec![T::zero(); self.nnz()];
+        for i in 0..row {
+            for (j, &value) in self.row(i) {
+                col_indices[next[j]] = i;
+                values[next[j]] = value;
+                next[j] += 1;
+            }
+        }
+        SparseMatrix {
+            dim: Vec2(col, row),
+            row_offsets,
+            col_indices,
+            values,
+        }
+    }
+
+    /// Multiplies the transpose of the `SparseMatrix` by a dense `Matrix` without forming the transpose
+    ///
+    /// Costs one row of `rhs` per stored item, the dense weight gradient of a sparse input.
+    ///
+    /// # Arguments
+    ///
+    /// - `rhs` - dense matrix with as many rows as the `SparseMatrix`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let sparse = ktensor::math::SparseMatrix::new(ktensor::math::Vec2(2, 3), vec![0, 1, 3], vec![2, 0, 1], vec![1.0, 2.0, 3.0]);
+    /// let dense = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 2), vec![1.0, 2.0, 3.0, 4.0]);
+    /// let expected = &sparse.to_dense().transpose() * &dense;
+    /// assert_eq!(sparse.transpose_mul(&dense).to_flattened(), expected.to_flattened());
+    /// ```
+    pub fn transpose_mul(&self, rhs: &Matrix<T>) -> Matrix<T> {
+        let Vec2(row, col) = self.dim;
+        let Vec2(rhs_row, rhs_col) = rhs.dim();
+        assert_eq!(row, rhs_row, "cannot multiply the transpose of a ({}, {}) sparse matrix by a ({}, {}) matrix", row, col, rhs_row, rhs_col);
+        if rhs_col == 0 {
+            return Matrix::new(Vec2(col, 0), Vec::new());
+        }
+        let owned: Vec<T>;
+        let b = if rhs.is_contiguous() {
+            rhs.storage()
+        } else {
+            owned = rhs.iter().collect();
+            &owned
+        };
+        let mut buffer = vec![T::zero(); col * rhs_col];
+        for (i, b_row) in b.chunks(rhs_col).enumerate().take(row) {
+            for (k, &value) in self.row(i) {
+                for (c, &b_ij) in buffer[k * rhs_col..(k + 1) * rhs_col].iter_mut().zip(b_row.iter()) {
+                    *c = *c + value * b_ij;
+                }
+            }
+        }
+        Matrix::new(Vec2(col, rhs_col), buffer)
+    }
+}
+
+////////////////////
+// Multiplication //
+////////////////////
+
+impl <T> Mul<&Matrix<T>> for &SparseMatrix<T> where T: Float {
+    type Output = Matrix<T>;
+
+    /// Multiplies a `SparseMatrix` by a dense `Matrix`, splitting rows across worker threads
+    ///
+    /// # Arguments
+    ///
+    /// - `rhs` - dense matrix with as many rows as the `SparseMatrix` has columns
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let sparse = ktensor::math::SparseMatrix::new(ktensor::math::Vec2(2, 3), vec![0, 1, 3], vec![2, 0, 1], vec![1.0, 2.0, 3.0]);
+    /// let dense = ktensor::math::Matrix::new(ktensor::math::Vec2(3, 2), (0..6).map(|i| i as f64).collect());
+    /// let expected = &sparse.to_dense() * &dense;
+    /// assert_eq!((&sparse * &dense).to_flattened(), expected.to_flattened());
+    /// ```
+    fn mul(self, rhs: &Matrix<T>) -> Matrix<T> {
+        let Vec2(row, col) = self.dim;
+        let Vec2(rhs_row, rhs_col) = rhs.dim();
+        assert_eq!(col, rhs_row, "cannot multiply a ({}, {}) sparse matrix by a ({}, {}) matrix", row, col, rhs_row, rhs_col);
+        if rhs_col == 0 {
+            return Matrix::new(Vec2(row, 0), Vec::new());
+        }
+        let owned: Vec<T>;
+        let b = if rhs.is_contiguous() {
+            rhs.storage()
+        } else {
+            owned = rhs.iter().collect();
+            &owned
+        };
+        let cost = (self.nnz() / row.max(1) + 1) * rhs_col;
+        let buffer = parallel::collect(row, cost, |range| {
+            let mut buf = vec![T::zero(); (range.end - range.start) * rhs_col];
+            for (i, c_row) in range.zip(buf.chunks_mut(rhs_col)) {
+                for (k, &value) in self.row(i) {
+                    for (c, &b_kj) in c_row.iter_mut().zip(b[k * rhs_col..(k + 1) * rhs_col].iter()) {
+                        *c = *c + value * b_kj;
+                    }
+                }
+            }
+            buf
+        });
+        Matrix::new(Vec2(row, rhs_col), buffer)
+    }
+}
+
+impl <T> Mul<Matrix<T>> for SparseMatrix<T> where T: Float {
+    type Output = Matrix<T>;
+
+    /// Multiplies a `SparseMatrix` by a dense `Matrix`
+    ///
+    /// # Arguments
+    ///
+    /// - `rhs` - dense matrix with as many rows as the `SparseMatrix` has columns
+    fn mul(self, rhs: Matrix<T>) -> Matrix<T> {
+        &self * &rhs
+    }
+}
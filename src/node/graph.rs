@@ -1,18 +1,77 @@
 use std::string::{String};
+use std::sync::{Arc};
+use std::collections::{HashSet};
 use math::{Vec2};
 use context::{Context};
 use tensor::{Tensor};
 
-pub trait Graph<T> where T: Copy {
+pub trait Graph<T>: Send + Sync where T: Copy {
     fn get_id(&self) -> String;
     fn get_dim(&self) -> Vec2;
-    fn run(&self, state: &Context<T>, variable: &Context<T>) -> Tensor<T>;
+
+    /// Returns `true` if this node is a `State`, whose value is updated by training
+    fn is_state(&self) -> bool {
+        false
+    }
+
+    /// Returns the nodes this node is computed from, in the order of the gradients of `backward_pass`
+    fn get_parameters(&self) -> Vec<Arc<dyn Graph<T>>> {
+        Vec::new()
+    }
+
+    /// Evaluates the graph, computing each node once even if it is consumed by several nodes
+    fn run(&self, state: &Context<T>, variable: &Context<T>) -> Tensor<T> {
+        let mut cache = Context::new();
+        self.evaluate(state, variable, &mut cache)
+    }
+
+    /// Evaluates the graph, recording the value of every node in `history`
+    ///
+    /// `history` is cleared first, so a `history` reused across passes never returns values of an earlier pass.
     fn train(&self, state: &Context<T>, variable: &Context<T>, history: &mut Context<T>) -> Tensor<T> {
+        history.clear();
+        self.evaluate(state, variable, history)
+    }
+
+    /// Evaluates this node within a pass, returning its value from `history` if it is already computed
+    ///
+    /// Nodes evaluate their parameters with `evaluate` from `forward_pass`, so a node consumed by several nodes
+    /// is computed once per pass.
+    fn evaluate(&self, state: &Context<T>, variable: &Context<T>, history: &mut Context<T>) -> Tensor<T> {
+        if let Some(tensor) = history.get(self.get_id()) {
+            return tensor.clone();
+        }
         let tensor = self.forward_pass(state, variable, history);
-        let tensor_clone = tensor.clone();
-        history.set(self.get_id(), tensor);
-        tensor_clone
+        history.set(self.get_id(), tensor.clone());
+        tensor
     }
+
+    /// Evaluates this node from the values of its parameters, evaluated with `evaluate`
     fn forward_pass(&self, state: &Context<T>, variable: &Context<T>, history: &mut Context<T>) -> Tensor<T>;
-    fn backward_pass(&self, state: &mut Context<T>, variable: &Context<T>, history: &Context<T>, gradient: &Tensor<T>, learning_rate: T);
+
+    /// Maps the gradient dC/dz of this node to the gradients of its parameters, using their values in `history`
+    ///
+    /// `gradient` is the sum over every consumer of this node, so the parameters are not visited again.
+    fn backward_pass(&self, _history: &Context<T>, _gradient: &Tensor<T>) -> Vec<Tensor<T>> {
+        Vec::new()
+    }
+}
+
+/// Returns every node of the graph ending at `node` once, with each node before the nodes it is computed from
+pub fn topological_order<T>(node: &Arc<dyn Graph<T>>) -> Vec<Arc<dyn Graph<T>>> where T: Copy {
+    let mut visited = HashSet::new();
+    let mut order = Vec::new();
+    // explicit stack of (node, whether its parameters are pushed), so deep graphs do not overflow
+    let mut stack = vec![(node.clone(), false)];
+    while let Some((node, expanded)) = stack.pop() {
+        if expanded {
+            order.push(node);
+        } else if visited.insert(node.get_id()) {
+            let parameters = node.get_parameters();
+            stack.push((node, true));
+            stack.extend(parameters.into_iter().rev().map(|parameter| (parameter, false)));
+        }
+    }
+    order.reverse();
+    order
 }
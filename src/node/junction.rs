@@ -1,17 +1,22 @@
 use std::string::{String};
 use std::sync::{Arc};
-use std::ops::{Mul, Add};
-use math::{Vec2};
+use math::{Vec2, Float};
 use context::{Context};
 use tensor::{Tensor};
 use node::{Graph};
 
+/// f(x, y) of a computation node
+pub type Operation<T> = fn(Vec<Tensor<T>>) -> Tensor<T>;
+
+/// f_x,y of a computation node, mapping dC/dz and the inputs x, y to dC/dx, dC/dy
+pub type OperationPrime<T> = fn(&Tensor<T>, Vec<&Tensor<T>>) -> Vec<Tensor<T>>;
+
 pub struct Node<T> {
     id: String,
     dim: Vec2,
-    op: fn(Vec<Tensor<T>>) -> Tensor<T>,
-    op_prime: fn(&Tensor<T>, Vec<&Tensor<T>>) -> Vec<Tensor<T>>,
-    param: Vec<Arc<Graph<T>>>,
+    op: Operation<T>,
+    op_prime: OperationPrime<T>,
+    param: Vec<Arc<dyn Graph<T>>>,
 }
 
 impl <T> Node<T> {
@@ -26,7 +31,7 @@ impl <T> Node<T> {
     /// - `operation_train`
     /// - `operation_prime` - f_x,y which takes in a gradient dC/dz and inputs x, y; outputs gradients dC/dx, dC/dy
     /// - `parameter` - Vec<(x, y)>
-    pub fn new(node_id: String, operation: fn(Vec<Tensor<T>>) -> Tensor<T>, operation_prime: fn(&Tensor<T>, Vec<&Tensor<T>>) -> Vec<Tensor<T>>, parameter: Vec<Arc<Graph<T>>>, calc_dim: fn(Vec<Vec2>) -> Vec2) -> Node<T> where T: Copy {
+    pub fn new(node_id: String, operation: Operation<T>, operation_prime: OperationPrime<T>, parameter: Vec<Arc<dyn Graph<T>>>, calc_dim: fn(Vec<Vec2>) -> Vec2) -> Node<T> where T: Copy {
         Node {
             id: node_id,
             dim: calc_dim(parameter.iter().map(|node| node.get_dim()).collect()),
@@ -36,7 +41,7 @@ impl <T> Node<T> {
         }
     }
 
-    pub fn with_dim(node_id: String, operation: fn(Vec<Tensor<T>>) -> Tensor<T>, operation_prime: fn(&Tensor<T>, Vec<&Tensor<T>>) -> Vec<Tensor<T>>, parameter: Vec<Arc<Graph<T>>>, dimension: Vec2) -> Node<T> {
+    pub fn with_dim(node_id: String, operation: Operation<T>, operation_prime: OperationPrime<T>, parameter: Vec<Arc<dyn Graph<T>>>, dimension: Vec2) -> Node<T> {
         Node {
             id: node_id,
             dim: dimension,
@@ -47,7 +52,7 @@ impl <T> Node<T> {
     }
 }
 
-impl <T> Graph<T> for Node<T> where T: Copy + Mul<Output=T> + Add<Output=T> {
+impl <T> Graph<T> for Node<T> where T: Float {
     fn get_id(&self) -> String {
         self.id.clone()
     }
@@ -56,23 +61,20 @@ impl <T> Graph<T> for Node<T> where T: Copy + Mul<Output=T> + Add<Output=T> {
         self.dim
     }
 
-    fn run(&self, state: &Context<T>, variable: &Context<T>) -> Tensor<T> {
-        (self.op)(self.param.iter().map(|node| node.run(state, variable)).collect())
-    }
-
     fn forward_pass(&self, state: &Context<T>, variable: &Context<T>, history: &mut Context<T>) -> Tensor<T> {
         (self.op)(self.param.iter().map(|node| {
-            node.train(state, variable, history)
+            node.evaluate(state, variable, history)
         }).collect())
     }
 
-    fn backward_pass(&self, state: &mut Context<T>, variable: &Context<T>, history: &Context<T>, gradient: &Tensor<T>, learning_rate: T) {
-        let deltas = (self.op_prime)(gradient, self.param.iter().map(|node| match history.get(node.get_id()) {
+    fn get_parameters(&self) -> Vec<Arc<dyn Graph<T>>> {
+        self.param.clone()
+    }
+
+    fn backward_pass(&self, history: &Context<T>, gradient: &Tensor<T>) -> Vec<Tensor<T>> {
+        (self.op_prime)(gradient, self.param.iter().map(|node| match history.get(node.get_id()) {
             Some(x) => x,
             None    => panic!("Node {} does not exist in history", node.get_id()),
-        }).collect());
-        for (delta, parameter) in deltas.iter().zip(self.param.iter()) {
-            parameter.backward_pass(state, variable, history, delta, learning_rate);
-        }
+        }).collect())
     }
 }
@@ -2,8 +2,10 @@ mod graph;
 mod junction;
 mod state;
 mod variable;
+mod sparse_variable;
 
-pub use self::graph::{Graph};
-pub use self::junction::{Node};
+pub use self::graph::{Graph, topological_order};
+pub use self::junction::{Node, Operation, OperationPrime};
 pub use self::state::{State};
 pub use self::variable::{Variable};
+pub use self::sparse_variable::{SparseVariable};
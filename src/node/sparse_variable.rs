@@ -0,0 +1,28 @@
+use std::string::{String};
+use math::{Vec2};
+
+/// A batch of mostly zero inputs, set with `Context::set_sparse` in the variable `Context`
+///
+/// A `SparseVariable` is not a `Graph` node: it is read directly by the ops that take sparse inputs, such as
+/// `op::sparse_dot`, so it is never converted to or from a dense `Tensor`.
+pub struct SparseVariable {
+    id: String,
+    dim: Vec2,
+}
+
+impl SparseVariable {
+    pub fn new(node_id: String, dimensions: Vec2) -> SparseVariable {
+        SparseVariable {
+            id: node_id,
+            dim: dimensions,
+        }
+    }
+
+    pub fn get_id(&self) -> String {
+        self.id.clone()
+    }
+
+    pub fn get_dim(&self) -> Vec2 {
+        self.dim
+    }
+}
@@ -1,21 +1,38 @@
 use std::string::{String};
 use std::sync::{Arc};
-use std::ops::{Mul, Add};
-use math::{Vec2};
+use math::{Vec2, Float};
 use node::{Graph};
 use tensor::{Tensor};
 use context::{Context};
+use init::{Initializer, HeNormal};
 
 pub struct State {
     id: String,
     dim: Vec2,
+    /// strategy for the starting values of the `State`
+    initializer: Box<dyn Initializer>,
 }
 
 impl State {
     pub fn new(node_id: String, dimensions: Vec2) -> State {
+        State::with_initializer(node_id, dimensions, HeNormal)
+    }
+
+    /// State node started by `initializer`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let bias = ktensor::State::with_initializer("bias".to_string(), ktensor::math::Vec2(1, 4), ktensor::init::Zeros);
+    /// let mut context = ktensor::Context::<f64>::new();
+    /// bias.initialize(&mut context);
+    /// assert_eq!(context.get(bias.get_id()).unwrap().clone().to_flattened(), vec![0.0; 4]);
+    /// ```
+    pub fn with_initializer<I>(node_id: String, dimensions: Vec2, initializer: I) -> State where I: Initializer + 'static {
         State {
             id: node_id,
             dim: dimensions,
+            initializer: Box::new(initializer),
         }
     }
 
@@ -23,28 +40,34 @@ impl State {
         self.id.clone()
     }
 
-    pub fn init_norm_f64(&self, context: &mut Context<f64>) {
-        context.set(self.get_id(), Tensor::<f64>::from_gaussian(self.dim, self.dim.0));
+    /// Sets the starting values of the `State` in `context`, same as `initialize`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let weight = ktensor::State::with_initializer("weight".to_string(), ktensor::math::Vec2(2, 2), ktensor::init::Constant(0.5));
+    /// let mut context = ktensor::Context::<f64>::new();
+    /// weight.init_norm(&mut context);
+    /// assert_eq!(context.get(weight.get_id()).unwrap().clone().to_flattened(), vec![0.5; 4]);
+    /// ```
+    pub fn init_norm<T>(&self, context: &mut Context<T>) where T: Float {
+        self.initialize(context);
     }
 
-    pub fn init_norm_f32(&self, context: &mut Context<f32>) {
-        context.set(self.get_id(), Tensor::<f32>::from_gaussian(self.dim, self.dim.0));
+    /// Sets the starting values of the `State` in `context` from its initializer
+    pub fn initialize<T>(&self, context: &mut Context<T>) where T: Float {
+        let values = self.initializer.sample(self.dim);
+        context.set(self.get_id(), Tensor::from_vec(self.dim, values.into_iter().map(T::from_f64).collect()));
     }
 
-    pub fn init_f64(vec_states: Vec<Arc<State>>, context: &mut Context<f64>) {
+    pub fn init<T>(vec_states: Vec<Arc<State>>, context: &mut Context<T>) where T: Float {
         for state in vec_states {
-            state.init_norm_f64(context);
-        }
-    }
-
-    pub fn init_f32(vec_states: Vec<Arc<State>>, context: &mut Context<f32>) {
-        for state in vec_states {
-            state.init_norm_f32(context);
+            state.initialize(context);
         }
     }
 }
 
-impl <T> Graph<T> for State where T: Copy + Mul<Output=T> + Add<Output=T> {
+impl <T> Graph<T> for State where T: Float {
     fn get_id(&self) -> String {
         self.id.clone()
     }
@@ -53,11 +76,8 @@ impl <T> Graph<T> for State where T: Copy + Mul<Output=T> + Add<Output=T> {
         self.dim
     }
 
-    fn run(&self, state: &Context<T>, _: &Context<T>) -> Tensor<T> {
-        match state.get(self.get_id()) {
-            Some(x) => x.clone(),
-            None    => panic!("State {} does not exist in state", self.get_id()),
-        }
+    fn is_state(&self) -> bool {
+        true
     }
 
     fn forward_pass(&self, state: &Context<T>, _: &Context<T>, _: &mut Context<T>) -> Tensor<T> {
@@ -66,13 +86,4 @@ impl <T> Graph<T> for State where T: Copy + Mul<Output=T> + Add<Output=T> {
             None    => panic!("State {} does not exist in state", self.get_id()),
         }
     }
-
-    fn backward_pass(&self, state: &mut Context<T>, _: &Context<T>, history: &Context<T>, gradient: &Tensor<T>, learning_rate: T) {
-        let delta = gradient * &learning_rate;
-        let previous_state = match history.get(self.get_id()) {
-            Some(x) => x,
-            None    => panic!("State {} does not exist in state", self.get_id()),
-        };
-        state.set(self.get_id(), previous_state + &delta);
-    }
 }
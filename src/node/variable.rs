@@ -1,7 +1,6 @@
 use std::string::{String};
 use std::sync::{Arc};
-use std::ops::{Mul, Add};
-use math::{Vec2};
+use math::{Vec2, Float};
 use node::{Graph};
 use tensor::{Tensor};
 use context::{Context};
@@ -23,28 +22,18 @@ impl Variable {
         self.id.clone()
     }
 
-    pub fn init_norm_f64(&self, context: &mut Context<f64>) {
-        context.set(self.id.clone(), Tensor::from_vec(self.dim, vec![0.0; self.dim.0 * self.dim.1]));
+    pub fn init_norm<T>(&self, context: &mut Context<T>) where T: Float {
+        context.set(self.id.clone(), Tensor::from_vec(self.dim, vec![T::zero(); self.dim.0 * self.dim.1]));
     }
 
-    pub fn init_norm_f32(&self, context: &mut Context<f32>) {
-        context.set(self.id.clone(), Tensor::from_vec(self.dim, vec![0.0; self.dim.0 * self.dim.1]));
-    }
-
-    pub fn init_f64(vec_variables: Vec<Arc<Variable>>, context: &mut Context<f64>) {
+    pub fn init<T>(vec_variables: Vec<Arc<Variable>>, context: &mut Context<T>) where T: Float {
         for variable in vec_variables {
-            variable.init_norm_f64(context);
-        }
-    }
-
-    pub fn init_f32(vec_variables: Vec<Arc<Variable>>, context: &mut Context<f32>) {
-        for variable in vec_variables {
-            variable.init_norm_f32(context);
+            variable.init_norm(context);
         }
     }
 }
 
-impl <T> Graph<T> for Variable where T: Copy + Mul<Output=T> + Add<Output=T> {
+impl <T> Graph<T> for Variable where T: Float {
     fn get_id(&self) -> String {
         self.id.clone()
     }
@@ -53,19 +42,10 @@ impl <T> Graph<T> for Variable where T: Copy + Mul<Output=T> + Add<Output=T> {
         self.dim
     }
 
-    fn run(&self, _: &Context<T>, variable: &Context<T>) -> Tensor<T> {
-        match variable.get(self.get_id()) {
-            Some(x) => x.clone(),
-            None    => panic!("Variable {} does not exist in variable", self.get_id()),
-        }
-    }
-
     fn forward_pass(&self, _: &Context<T>, variable: &Context<T>, _: &mut Context<T>) -> Tensor<T> {
         match variable.get(self.get_id()) {
             Some(x) => x.clone(),
             None    => panic!("Variable {} does not exist in variable", self.get_id()),
         }
     }
-
-    fn backward_pass(&self, _: &mut Context<T>, _: &Context<T>, _: &Context<T>, _: &Tensor<T>, _: T) {}
 }
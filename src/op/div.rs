@@ -0,0 +1,30 @@
+use std::string::{String};
+use std::sync::{Arc};
+use math::{Vec2, Float};
+use node::{Node, Graph};
+use tensor::{Tensor};
+
+fn operation<T>(vec: Vec<Tensor<T>>) -> Tensor<T> where T: Float {
+    &vec[0] / &vec[1]
+}
+
+fn operation_prime<T>(gradient: &Tensor<T>, vec: Vec<&Tensor<T>>) -> Vec<Tensor<T>> where T: Float {
+    // d(a / b) = da / b - a db / b^2
+    let quotient = vec[0] / vec[1];
+    let delta = gradient / vec[1];
+    vec![delta.sum_to(vec[0].dim()), (-&delta.product(&quotient)).sum_to(vec[1].dim())]
+}
+
+fn calc_dim(dims: Vec<Vec2>) -> Vec2 {
+    let Vec2(x1, y1) = dims[0];
+    let Vec2(x2, y2) = dims[1];
+    match dims[0].broadcast(dims[1]) {
+        Some(dim) => dim,
+        None      => panic!("cannot divide dimensions ({}, {}) and ({}, {})", x1, y1, x2, y2),
+    }
+}
+
+/// Elementwise quotient of `a` and `b`, broadcasting axes of length 1
+pub fn div<T>(node_id: String, a: Arc<dyn Graph<T>>, b: Arc<dyn Graph<T>>) -> Node<T> where T: Float {
+    Node::new(node_id, operation, operation_prime, vec![a, b], calc_dim)
+}
@@ -1,15 +1,14 @@
 use std::string::{String};
 use std::sync::{Arc};
-use std::ops::{Add, Mul};
-use math::{Vec2};
+use math::{Vec2, Float};
 use node::{Node, Graph};
 use tensor::{Tensor};
 
-fn operation<T>(vec: Vec<Tensor<T>>) -> Tensor<T> where T: Mul<Output=T> + Add<Output=T> + Copy {
+fn operation<T>(vec: Vec<Tensor<T>>) -> Tensor<T> where T: Float {
     &vec[0] * &vec[1]
 }
 
-fn operation_prime<T>(gradient: &Tensor<T>, vec: Vec<&Tensor<T>>) -> Vec<Tensor<T>> where T: Mul<Output=T> + Add<Output=T> + Copy {
+fn operation_prime<T>(gradient: &Tensor<T>, vec: Vec<&Tensor<T>>) -> Vec<Tensor<T>> where T: Float {
     vec![gradient * &vec[1].transpose(), &vec[0].transpose() * gradient]
 }
 
@@ -20,6 +19,6 @@ fn calc_dim(vec: Vec<Vec2>) -> Vec2 {
     Vec2(x1, y2)
 }
 
-pub fn dot<T>(node_id: String, a: Arc<Graph<T>>, b: Arc<Graph<T>>) -> Node<T> where T: Mul<Output=T> + Add<Output=T> + Copy {
+pub fn dot<T>(node_id: String, a: Arc<dyn Graph<T>>, b: Arc<dyn Graph<T>>) -> Node<T> where T: Float {
     Node::new(node_id, operation, operation_prime, vec![a, b], calc_dim)
 }
@@ -1,11 +1,19 @@
 mod dot;
+mod sparse_dot;
 mod add;
+mod sub;
+mod mul;
+mod div;
 mod softmax;
 mod relu;
 mod sigmoid;
 
 pub use self::dot::{dot};
+pub use self::sparse_dot::{sparse_dot, SparseDot};
 pub use self::add::{add};
-pub use self::softmax::{softmax_f64, softmax_f32, softmax_round_f64, softmax_round_f32};
-pub use self::relu::{relu_f64, relu_f32};
-pub use self::sigmoid::{sigmoid_f64, sigmoid_f32};
+pub use self::sub::{sub};
+pub use self::mul::{mul};
+pub use self::div::{div};
+pub use self::softmax::{softmax, softmax_round};
+pub use self::relu::{relu};
+pub use self::sigmoid::{sigmoid};
@@ -0,0 +1,27 @@
+use std::string::{String};
+use std::sync::{Arc};
+use math::{Vec2, Float};
+use node::{Node, Graph};
+use tensor::{Tensor};
+
+fn operation<T>(vec: Vec<Tensor<T>>) -> Tensor<T> where T: Float {
+    vec[0].product(&vec[1])
+}
+
+fn operation_prime<T>(gradient: &Tensor<T>, vec: Vec<&Tensor<T>>) -> Vec<Tensor<T>> where T: Float {
+    vec![gradient.product(vec[1]).sum_to(vec[0].dim()), gradient.product(vec[0]).sum_to(vec[1].dim())]
+}
+
+fn calc_dim(dims: Vec<Vec2>) -> Vec2 {
+    let Vec2(x1, y1) = dims[0];
+    let Vec2(x2, y2) = dims[1];
+    match dims[0].broadcast(dims[1]) {
+        Some(dim) => dim,
+        None      => panic!("cannot multiply dimensions ({}, {}) and ({}, {})", x1, y1, x2, y2),
+    }
+}
+
+/// Elementwise product of `a` and `b`, broadcasting axes of length 1
+pub fn mul<T>(node_id: String, a: Arc<dyn Graph<T>>, b: Arc<dyn Graph<T>>) -> Node<T> where T: Float {
+    Node::new(node_id, operation, operation_prime, vec![a, b], calc_dim)
+}
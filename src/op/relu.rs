@@ -1,65 +1,44 @@
 use std::string::{String};
 use std::sync::{Arc};
-use math::{Vec2};
+use math::{Vec2, Float};
 use node::{Node, Graph};
 use tensor::{Tensor};
 
-fn operation_f64(vec: Vec<Tensor<f64>>) -> Tensor<f64> {
-    Tensor::from_vec(vec[0].dim(), vec[0].buffer().iter().map(|&a| {
-        if a > 16.0 {
+fn operation<T>(vec: Vec<Tensor<T>>) -> Tensor<T> where T: Float {
+    let bound = T::from_f64(16.0);
+    let leak = T::from_f64(64.0);
+    let scale = T::from_f64(0.984375);
+    vec[0].map(|a| {
+        if a > bound {
             a
-        } else if a < -16.0 {
-            a / 64.0
+        } else if a < -bound {
+            a / leak
         } else {
-            a / 64.0 + 0.984375 * (1.0 + a.exp()).ln()
+            a / leak + scale * (T::one() + a.exp()).ln()
         }
-    }).collect())
+    })
 }
 
-fn operation_f32(vec: Vec<Tensor<f32>>) -> Tensor<f32> {
-    Tensor::from_vec(vec[0].dim(), vec[0].buffer().iter().map(|&a| {
-        if a > 16.0 {
-            a
-        } else if a < -16.0 {
-            a / 64.0
-        } else {
-            a / 64.0 + 0.984375 * (1.0 + a.exp()).ln()
-        }
-    }).collect())
-}
-
-fn operation_prime_f64(gradient: &Tensor<f64>, vec: Vec<&Tensor<f64>>) -> Vec<Tensor<f64>> {
-    vec![Tensor::from_vec(gradient.dim(), vec[0].buffer().iter().zip(gradient.buffer().iter()).map(|(&a, &g)| {
-        if a > 16.0 {
-            g
-        } else if a < -16.0 {
-            g / 64.0
-        } else {
-            g * (0.015625 + 0.984375 * (1.0 + (-a).exp()).recip())
-        }
-    }).collect())]
-}
-
-fn operation_prime_f32(gradient: &Tensor<f32>, vec: Vec<&Tensor<f32>>) -> Vec<Tensor<f32>> {
-    vec![Tensor::from_vec(gradient.dim(), vec[0].buffer().iter().zip(gradient.buffer().iter()).map(|(&a, &g)| {
-        if a > 16.0 {
+fn operation_prime<T>(gradient: &Tensor<T>, vec: Vec<&Tensor<T>>) -> Vec<Tensor<T>> where T: Float {
+    let bound = T::from_f64(16.0);
+    let leak = T::from_f64(64.0);
+    let slope = T::from_f64(0.015625);
+    let scale = T::from_f64(0.984375);
+    vec![vec[0].zip_map(gradient, |a, g| {
+        if a > bound {
             g
-        } else if a < -16.0 {
-            g / 64.0
+        } else if a < -bound {
+            g / leak
         } else {
-            g * (0.015625 + 0.984375 * (1.0 + (-a).exp()).recip())
+            g * (slope + scale * (T::one() + (-a).exp()).recip())
         }
-    }).collect())]
+    })]
 }
 
 fn calc_dim(dims: Vec<Vec2>) -> Vec2 {
     dims[0]
 }
 
-pub fn relu_f64(node_id: String, z: Arc<Graph<f64>>) -> Node<f64> {
-    Node::new(node_id, operation_f64, operation_prime_f64, vec![z], calc_dim)
-}
-
-pub fn relu_f32(node_id: String, z: Arc<Graph<f32>>) -> Node<f32> {
-    Node::new(node_id, operation_f32, operation_prime_f32, vec![z], calc_dim)
+pub fn relu<T>(node_id: String, z: Arc<dyn Graph<T>>) -> Node<T> where T: Float {
+    Node::new(node_id, operation, operation_prime, vec![z], calc_dim)
 }
@@ -1,69 +1,34 @@
 use std::string::{String};
 use std::sync::{Arc};
-use math::{Vec2};
+use math::{Vec2, Float};
 use node::{Node, Graph};
 use tensor::{Tensor};
 
-fn operation_f64(vec: Vec<Tensor<f64>>) -> Tensor<f64> {
-    Tensor::from_vec(vec[0].dim(), vec[0].buffer().iter().map(|&a| {
-        if a >= 0.0 {
-            let z = (-a).exp();
-            1.0 / (1.0 + z)
-        } else {
-            let z = a.exp();
-            z / (1.0 + z)
-        }
-    }).collect())
+fn logistic<T>(a: T) -> T where T: Float {
+    if a >= T::zero() {
+        let z = (-a).exp();
+        T::one() / (T::one() + z)
+    } else {
+        let z = a.exp();
+        z / (T::one() + z)
+    }
 }
 
-fn operation_f32(vec: Vec<Tensor<f32>>) -> Tensor<f32> {
-    Tensor::from_vec(vec[0].dim(), vec[0].buffer().iter().map(|&a| {
-        if a >= 0.0 {
-            let z = (-a).exp();
-            1.0 / (1.0 + z)
-        } else {
-            let z = a.exp();
-            z / (1.0 + z)
-        }
-    }).collect())
+fn operation<T>(vec: Vec<Tensor<T>>) -> Tensor<T> where T: Float {
+    vec[0].map(logistic)
 }
 
-fn operation_prime_f64(gradient: &Tensor<f64>, vec: Vec<&Tensor<f64>>) -> Vec<Tensor<f64>> {
-    vec![Tensor::from_vec(gradient.dim(), vec[0].buffer().iter().zip(gradient.buffer().iter()).map(|(&a, &g)| {
-        let mut z;
-        if a >= 0.0 {
-            z = (-a).exp();
-            z = 1.0 / (1.0 + z);
-        } else {
-            z = a.exp();
-            z = z / (1.0 + z);
-        }
-        g * z * (1.0 - z)
-    }).collect())]
-}
-
-fn operation_prime_f32(gradient: &Tensor<f32>, vec: Vec<&Tensor<f32>>) -> Vec<Tensor<f32>> {
-    vec![Tensor::from_vec(gradient.dim(), vec[0].buffer().iter().zip(gradient.buffer().iter()).map(|(&a, &g)| {
-        let mut z;
-        if a >= 0.0 {
-            z = (-a).exp();
-            z = 1.0 / (1.0 + z);
-        } else {
-            z = a.exp();
-            z = z / (1.0 + z);
-        }
-        g * z * (1.0 - z)
-    }).collect())]
+fn operation_prime<T>(gradient: &Tensor<T>, vec: Vec<&Tensor<T>>) -> Vec<Tensor<T>> where T: Float {
+    vec![vec[0].zip_map(gradient, |a, g| {
+        let z = logistic(a);
+        g * z * (T::one() - z)
+    })]
 }
 
 fn calc_dim(dims: Vec<Vec2>) -> Vec2 {
     dims[0]
 }
 
-pub fn sigmoid_f64(node_id: String, z: Arc<Graph<f64>>) -> Node<f64> {
-    Node::new(node_id, operation_f64, operation_prime_f64, vec![z], calc_dim)
-}
-
-pub fn sigmoid_f32(node_id: String, z: Arc<Graph<f32>>) -> Node<f32> {
-    Node::new(node_id, operation_f32, operation_prime_f32, vec![z], calc_dim)
+pub fn sigmoid<T>(node_id: String, z: Arc<dyn Graph<T>>) -> Node<T> where T: Float {
+    Node::new(node_id, operation, operation_prime, vec![z], calc_dim)
 }
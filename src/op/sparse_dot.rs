@@ -0,0 +1,94 @@
+use std::string::{String};
+use std::sync::{Arc};
+use math::{Vec2, Float};
+use node::{Graph, SparseVariable};
+use tensor::{Tensor};
+use context::{Context};
+
+/// Product of a `SparseVariable` and a dense node, built by `sparse_dot`
+pub struct SparseDot<T> {
+    id: String,
+    dim: Vec2,
+    a: Arc<SparseVariable>,
+    b: Arc<dyn Graph<T>>,
+}
+
+impl <T> Graph<T> for SparseDot<T> where T: Float {
+    fn get_id(&self) -> String {
+        self.id.clone()
+    }
+
+    fn get_dim(&self) -> Vec2 {
+        self.dim
+    }
+
+    fn get_parameters(&self) -> Vec<Arc<dyn Graph<T>>> {
+        vec![self.b.clone()]
+    }
+
+    /// Multiplies the sparse value of `a` in `variable` by `b`, keeping it in `history` for `backward_pass`
+    fn forward_pass(&self, state: &Context<T>, variable: &Context<T>, history: &mut Context<T>) -> Tensor<T> {
+        let b = self.b.evaluate(state, variable, history);
+        let a = match variable.get_sparse(self.a.get_id()) {
+            Some(x) => x.clone(),
+            None    => panic!("SparseVariable {} does not exist in variable", self.a.get_id()),
+        };
+        // rows are the batch size, which a placeholder of `Vec2(0, n)` leaves open
+        assert_eq!(a.dim().1, self.a.get_dim().1, "SparseVariable {} has the wrong number of columns", self.a.get_id());
+        let matrix = &a * b.matrix();
+        history.set_sparse(self.a.get_id(), a);
+        Tensor::new(matrix.dim(), matrix)
+    }
+
+    fn backward_pass(&self, history: &Context<T>, gradient: &Tensor<T>) -> Vec<Tensor<T>> {
+        let a = match history.get_sparse(self.a.get_id()) {
+            Some(x) => x,
+            None    => panic!("SparseVariable {} does not exist in history", self.a.get_id()),
+        };
+        let delta = a.transpose_mul(gradient.matrix());
+        vec![Tensor::new(delta.dim(), delta)]
+    }
+}
+
+/// Multiplies a mostly zero input `a` by a dense `b`
+///
+/// The value of `a` is read as a `SparseMatrix` from the variable `Context`, so it is never converted from a
+/// dense `Tensor`, and only its nonzero items are multiplied. `a` is data rather than a node of the graph,
+/// so only `b` receives a gradient.
+///
+/// # Example
+///
+/// ```
+/// use ktensor::math::{Vec2, SparseMatrix};
+/// let a = ktensor::Arc::new(ktensor::SparseVariable::new("a".to_string(), Vec2(1, 3)));
+/// let b = ktensor::Arc::new(ktensor::State::new("b".to_string(), Vec2(3, 1)));
+/// let c = ktensor::Arc::new(ktensor::op::sparse_dot("c".to_string(), a.clone(), b.clone()));
+///
+/// let mut state = ktensor::Context::new();
+/// state.set("b".to_string(), ktensor::Tensor::from_vec(Vec2(3, 1), vec![1.0, 2.0, 3.0]));
+/// let mut variable = ktensor::Context::new();
+/// variable.set_sparse("a".to_string(), SparseMatrix::from_triplets(Vec2(1, 3), vec![(0, 2, 2.0), (0, 0, -1.0)]));
+///
+/// assert_eq!(ktensor::execute(c.clone(), &state, &variable).to_flattened(), vec![5.0]);
+/// let gradients = ktensor::gradients(c, &state, &variable);
+/// assert_eq!(gradients.get("b".to_string()).unwrap().clone().to_flattened(), vec![-1.0, 0.0, 2.0]);
+///
+/// // a batch of any size feeds a `Vec2(0, n)` placeholder
+/// let batch = ktensor::Arc::new(ktensor::SparseVariable::new("batch".to_string(), Vec2(0, 3)));
+/// let d = ktensor::Arc::new(ktensor::op::sparse_dot("d".to_string(), batch, b));
+/// variable.set_sparse("batch".to_string(), SparseMatrix::from_triplets(Vec2(2, 3), vec![(0, 1, 1.0), (1, 2, -1.0)]));
+/// let product = ktensor::execute(d, &state, &variable);
+/// assert_eq!(product.dim(), Vec2(2, 1));
+/// assert_eq!(product.to_flattened(), vec![2.0, -3.0]);
+/// ```
+pub fn sparse_dot<T>(node_id: String, a: Arc<SparseVariable>, b: Arc<dyn Graph<T>>) -> SparseDot<T> where T: Float {
+    let Vec2(x1, y1) = a.get_dim();
+    let Vec2(x2, y2) = b.get_dim();
+    assert_eq!(y1, x2);
+    SparseDot {
+        id: node_id,
+        dim: Vec2(x1, y2),
+        a,
+        b,
+    }
+}
@@ -0,0 +1,187 @@
+use std::collections::{HashMap};
+use math::{Float};
+use context::{Context};
+use tensor::{Tensor};
+use optimizer::{Optimizer, value};
+
+/// Gradient descent scaled by running estimates of the first and second moments of each gradient
+///
+/// With m = `beta1 * m + (1 - beta1) * g` and v = `beta2 * v + (1 - beta2) * g^2`, each `State` moves by
+/// `rate * m' / (sqrt(v') + epsilon)`, where m' and v' are divided by `1 - beta^t` to correct their bias towards 0 after t steps of the `State`.
+/// With `amsgrad`, v' is computed from the largest v so far.
+///
+/// # Example
+///
+/// ```
+/// use ktensor::optimizer::Optimizer;
+/// let mut state = ktensor::Context::new();
+/// state.set("w".to_string(), ktensor::Tensor::from_vec(ktensor::Vec2(1, 2), vec![1.0f64, 1.0]));
+/// let mut gradients = ktensor::Context::new();
+/// gradients.set("w".to_string(), ktensor::Tensor::from_vec(ktensor::Vec2(1, 2), vec![0.001, -100.0]));
+///
+/// // the first step moves every item by about the rate, whatever the scale of its gradient
+/// let mut adam = ktensor::optimizer::Adam::new(-0.1);
+/// adam.step(&mut state, &gradients);
+/// let w = state.get("w".to_string()).unwrap().clone().to_flattened();
+/// assert!((w[0] - 0.9).abs() < 1e-4);
+/// assert!((w[1] - 1.1).abs() < 1e-4);
+/// ```
+pub struct Adam<T> {
+    pub rate: T,
+    pub beta1: T,
+    pub beta2: T,
+    pub epsilon: T,
+    /// normalize by the largest second moment so far
+    pub amsgrad: bool,
+    /// number of steps taken keyed by the id of their `State`
+    steps: HashMap<String, i32>,
+    /// first moments keyed by the id of their `State`
+    means: Context<T>,
+    /// second moments keyed by the id of their `State`
+    variances: Context<T>,
+    /// largest second moments keyed by the id of their `State`, with `amsgrad`
+    max_variances: Context<T>,
+}
+
+impl <T> Adam<T> where T: Float {
+    /// Adam with `beta1` 0.9, `beta2` 0.999 and `epsilon` 1e-8
+    ///
+    /// # Arguments
+    ///
+    /// - `rate` - negative learning rate, typically -0.001
+    pub fn new(rate: T) -> Adam<T> {
+        Adam {
+            rate,
+            beta1: T::from_f64(0.9),
+            beta2: T::from_f64(0.999),
+            epsilon: T::from_f64(1e-8),
+            amsgrad: false,
+            steps: HashMap::new(),
+            means: Context::new(),
+            variances: Context::new(),
+            max_variances: Context::new(),
+        }
+    }
+
+    /// Returns the first moment estimate of the `State` `nodeid`, if it has been updated
+    pub fn mean(&self, nodeid: String) -> Option<&Tensor<T>> {
+        self.means.get(nodeid)
+    }
+
+    /// Returns the second moment estimate of the `State` `nodeid`, if it has been updated
+    pub fn variance(&self, nodeid: String) -> Option<&Tensor<T>> {
+        self.variances.get(nodeid)
+    }
+
+    /// Returns the number of steps taken by the `State` `nodeid`
+    ///
+    /// Each `State` corrects the bias of its moments by its own count, so a `State` first updated after
+    /// others still takes a full first step.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ktensor::optimizer::Optimizer;
+    /// let mut state = ktensor::Context::new();
+    /// state.set("a".to_string(), ktensor::Tensor::from_vec(ktensor::Vec2(1, 1), vec![1.0f64]));
+    /// state.set("b".to_string(), ktensor::Tensor::from_vec(ktensor::Vec2(1, 1), vec![1.0f64]));
+    /// let mut gradients = ktensor::Context::new();
+    /// gradients.set("a".to_string(), ktensor::Tensor::from_vec(ktensor::Vec2(1, 1), vec![1.0]));
+    ///
+    /// let mut adam = ktensor::optimizer::Adam::new(-0.1);
+    /// for _ in 0..10 {
+    ///     adam.step(&mut state, &gradients);
+    /// }
+    /// gradients.set("b".to_string(), ktensor::Tensor::from_vec(ktensor::Vec2(1, 1), vec![1.0]));
+    /// adam.step(&mut state, &gradients);
+    /// assert_eq!((adam.steps("a".to_string()), adam.steps("b".to_string())), (11, 1));
+    /// assert!((state.get("b".to_string()).unwrap().get(ktensor::Vec2(0, 0)) - 0.9).abs() < 1e-6);
+    /// ```
+    pub fn steps(&self, nodeid: String) -> i32 {
+        self.steps.get(&nodeid).cloned().unwrap_or(0)
+    }
+}
+
+impl <T> Optimizer<T> for Adam<T> where T: Float {
+    fn step(&mut self, state: &mut Context<T>, gradients: &Context<T>) {
+        let (beta1, beta2, epsilon) = (self.beta1, self.beta2, self.epsilon);
+        for (id, gradient) in gradients.iter() {
+            let steps = self.steps.entry(id.clone()).or_insert(0);
+            *steps += 1;
+            let correction1 = T::one() - beta1.powi(*steps);
+            let correction2 = T::one() - beta2.powi(*steps);
+            let mean = match self.means.get(id.clone()) {
+                Some(m) => m.zip_map(gradient, |m, g| beta1 * m + (T::one() - beta1) * g),
+                None    => gradient.map(|g| (T::one() - beta1) * g),
+            };
+            let variance = match self.variances.get(id.clone()) {
+                Some(v) => v.zip_map(gradient, |v, g| beta2 * v + (T::one() - beta2) * g * g),
+                None    => gradient.map(|g| (T::one() - beta2) * g * g),
+            };
+            let normalizer = if self.amsgrad {
+                let max_variance = match self.max_variances.get(id.clone()) {
+                    Some(v) => v.zip_map(&variance, |a, b| a.max(b)),
+                    None    => variance.clone(),
+                };
+                self.max_variances.set(id.clone(), max_variance.clone());
+                max_variance
+            } else {
+                variance.clone()
+            };
+            let rate = self.rate / correction1;
+            let delta = mean.zip_map(&normalizer, |m, v| rate * m / ((v / correction2).sqrt() + epsilon));
+            *value(state, id) += &delta;
+            self.means.set(id.clone(), mean);
+            self.variances.set(id.clone(), variance);
+        }
+    }
+}
+
+/// `Adam` with weight decay decoupled from the gradient
+///
+/// Before the `Adam` step, each `State` w moves by `rate * weight_decay * w`,
+/// so the decay is not scaled by the moment estimates like an L2 term of the cost would be.
+///
+/// # Example
+///
+/// ```
+/// use ktensor::optimizer::Optimizer;
+/// let mut state = ktensor::Context::new();
+/// state.set("w".to_string(), ktensor::Tensor::from_vec(ktensor::Vec2(1, 1), vec![2.0f64]));
+/// let mut gradients = ktensor::Context::new();
+/// gradients.set("w".to_string(), ktensor::Tensor::from_vec(ktensor::Vec2(1, 1), vec![0.0]));
+///
+/// let mut adamw = ktensor::optimizer::AdamW::new(-0.1, 0.5);
+/// adamw.step(&mut state, &gradients);
+/// assert!((state.get("w".to_string()).unwrap().get(ktensor::Vec2(0, 0)) - 1.9).abs() < 1e-12);
+/// ```
+pub struct AdamW<T> {
+    pub adam: Adam<T>,
+    pub weight_decay: T,
+}
+
+impl <T> AdamW<T> where T: Float {
+    /// AdamW with the defaults of `Adam::new`
+    ///
+    /// # Arguments
+    ///
+    /// - `rate` - negative learning rate, typically -0.001
+    /// - `weight_decay` - fraction of each `State` decayed per unit of rate, typically 0.01
+    pub fn new(rate: T, weight_decay: T) -> AdamW<T> {
+        AdamW {
+            adam: Adam::new(rate),
+            weight_decay,
+        }
+    }
+}
+
+impl <T> Optimizer<T> for AdamW<T> where T: Float {
+    fn step(&mut self, state: &mut Context<T>, gradients: &Context<T>) {
+        let decay = T::one() + self.adam.rate * self.weight_decay;
+        for (id, _) in gradients.iter() {
+            let x = value(state, id);
+            *x = &*x * &decay;
+        }
+        self.adam.step(state, gradients);
+    }
+}
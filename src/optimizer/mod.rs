@@ -0,0 +1,26 @@
+use std::string::{String};
+use context::{Context};
+use tensor::{Tensor};
+
+mod momentum;
+mod adam;
+
+pub use self::momentum::{Momentum};
+pub use self::adam::{Adam, AdamW};
+
+/// Rule updating the value of each `State` from its gradient dC/dState
+///
+/// Gradients and the buffers of an `Optimizer` are keyed by the id of their `State`, as returned by `run::gradients`.
+/// Rates are negative to descend the cost.
+pub trait Optimizer<T> where T: Copy {
+    /// Updates the value in `state` of every `State` in `gradients`
+    fn step(&mut self, state: &mut Context<T>, gradients: &Context<T>);
+}
+
+/// Returns the value of the `State` `nodeid` to update
+fn value<'a, T>(state: &'a mut Context<T>, nodeid: &str) -> &'a mut Tensor<T> where T: Copy {
+    match state.get_mut(String::from(nodeid)) {
+        Some(x) => x,
+        None    => panic!("State {} does not exist in state", nodeid),
+    }
+}
@@ -0,0 +1,75 @@
+use math::{Float};
+use context::{Context};
+use tensor::{Tensor};
+use optimizer::{Optimizer, value};
+
+/// Gradient descent accumulating a velocity for each `State`
+///
+/// The velocity starts at the first gradient g and then becomes `momentum * v + (1 - dampening) * g`.
+/// Each `State` moves by `rate * v`, or by `rate * (g + momentum * v)` with `nesterov`.
+///
+/// # Example
+///
+/// ```
+/// use ktensor::optimizer::Optimizer;
+/// let mut state = ktensor::Context::new();
+/// state.set("w".to_string(), ktensor::Tensor::from_vec(ktensor::Vec2(1, 1), vec![1.0f64]));
+/// let mut gradients = ktensor::Context::new();
+/// gradients.set("w".to_string(), ktensor::Tensor::from_vec(ktensor::Vec2(1, 1), vec![2.0]));
+///
+/// let mut momentum = ktensor::optimizer::Momentum::new(-0.1, 0.5);
+/// momentum.step(&mut state, &gradients);
+/// momentum.step(&mut state, &gradients);
+/// assert_eq!(momentum.velocity("w".to_string()).unwrap().get(ktensor::Vec2(0, 0)), 3.0);
+/// assert!((state.get("w".to_string()).unwrap().get(ktensor::Vec2(0, 0)) - 0.5).abs() < 1e-12);
+/// ```
+pub struct Momentum<T> {
+    pub rate: T,
+    pub momentum: T,
+    pub dampening: T,
+    /// look ahead along the velocity before stepping
+    pub nesterov: bool,
+    /// velocities keyed by the id of their `State`
+    velocities: Context<T>,
+}
+
+impl <T> Momentum<T> where T: Float {
+    /// Momentum without dampening or Nesterov acceleration
+    ///
+    /// # Arguments
+    ///
+    /// - `rate` - negative learning rate
+    /// - `momentum` - fraction of the velocity kept at each step, typically 0.9
+    pub fn new(rate: T, momentum: T) -> Momentum<T> {
+        Momentum {
+            rate,
+            momentum,
+            dampening: T::zero(),
+            nesterov: false,
+            velocities: Context::new(),
+        }
+    }
+
+    /// Returns the velocity of the `State` `nodeid`, if it has been updated
+    pub fn velocity(&self, nodeid: String) -> Option<&Tensor<T>> {
+        self.velocities.get(nodeid)
+    }
+}
+
+impl <T> Optimizer<T> for Momentum<T> where T: Float {
+    fn step(&mut self, state: &mut Context<T>, gradients: &Context<T>) {
+        for (id, gradient) in gradients.iter() {
+            let velocity = match self.velocities.get(id.clone()) {
+                Some(v) => &(v * &self.momentum) + &(gradient * &(T::one() - self.dampening)),
+                None    => gradient.clone(),
+            };
+            let direction = if self.nesterov {
+                gradient + &(&velocity * &self.momentum)
+            } else {
+                velocity.clone()
+            };
+            *value(state, id) += &(&direction * &self.rate);
+            self.velocities.set(id.clone(), velocity);
+        }
+    }
+}
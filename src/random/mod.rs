@@ -0,0 +1,80 @@
+extern crate rand;
+use self::rand::{Rng, SeedableRng, Isaac64Rng, thread_rng};
+use self::rand::distributions::normal::{Normal};
+use self::rand::distributions::range::{Range};
+use self::rand::distributions::{IndependentSample};
+
+use std::sync::{Mutex, MutexGuard, OnceLock};
+
+/// Returns the generator shared by every thread, seeded from entropy until `set_seed` is called
+fn generator() -> MutexGuard<'static, Isaac64Rng> {
+    static RNG: OnceLock<Mutex<Isaac64Rng>> = OnceLock::new();
+    let rng = RNG.get_or_init(|| Mutex::new(Isaac64Rng::from_seed(&[thread_rng().gen::<u64>()])));
+    // a panic while drawing cannot leave the generator in an invalid state
+    rng.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Seeds the random number generator of `ktensor`
+///
+/// Initializers, `Tensor::from_gaussian` and any other random values in `ktensor` are drawn from a single
+/// generator shared by every thread, so seeding it on one thread also seeds the draws of other threads.
+/// The same seed reproduces the values bit for bit as long as they are drawn in the same order,
+/// so draws made concurrently from several threads are only reproducible as a whole.
+///
+/// # Arguments
+///
+/// - `seed` - seed of the generator
+///
+/// # Example
+///
+/// ```
+/// ktensor::random::set_seed(42);
+/// let tensor1 = ktensor::Tensor::<f64>::from_gaussian(ktensor::math::Vec2(3, 3), 3).to_flattened();
+/// ktensor::random::set_seed(42);
+/// let tensor2 = ktensor::Tensor::<f64>::from_gaussian(ktensor::math::Vec2(3, 3), 3).to_flattened();
+/// assert_eq!(tensor1, tensor2);
+/// ```
+pub fn set_seed(seed: u64) {
+    *generator() = Isaac64Rng::from_seed(&[seed]);
+}
+
+/// Returns `len` values from a normal distribution
+///
+/// # Arguments
+///
+/// - `len` - number of values
+/// - `mean` - mean of the distribution
+/// - `std_dev` - standard deviation of the distribution
+///
+/// # Example
+///
+/// ```
+/// ktensor::random::set_seed(7);
+/// let values = ktensor::random::normal(4, 1.0, 0.0);
+/// assert_eq!(values, vec![1.0; 4]);
+/// ```
+pub fn normal(len: usize, mean: f64, std_dev: f64) -> Vec<f64> {
+    let normal = Normal::new(mean, std_dev);
+    let mut rng = generator();
+    (0..len).map(|_| normal.ind_sample(&mut *rng)).collect()
+}
+
+/// Returns `len` values from a uniform distribution over `[low, high)`
+///
+/// # Arguments
+///
+/// - `len` - number of values
+/// - `low` - lowest value
+/// - `high` - bound above every value
+///
+/// # Example
+///
+/// ```
+/// let values = ktensor::random::uniform(100, -1.0, 1.0);
+/// assert!(values.iter().all(|&x| -1.0 <= x && x < 1.0));
+/// ```
+pub fn uniform(len: usize, low: f64, high: f64) -> Vec<f64> {
+    let range = Range::new(low, high);
+    let mut rng = generator();
+    (0..len).map(|_| range.ind_sample(&mut *rng)).collect()
+}
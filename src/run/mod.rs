@@ -1,14 +1,109 @@
 use std::sync::{Arc};
-use node::{Graph};
+use node::{Graph, topological_order};
 use context::{Context};
 use tensor::{Tensor};
-use math::{Vec2};
+use math::{Vec2, Float};
+use optimizer::{Optimizer};
 
-pub fn execute<T>(node: Arc<Graph<T>>, state: &Context<T>, variables: &Context<T>) -> Tensor<T> where T: Copy {
+pub fn execute<T>(node: Arc<dyn Graph<T>>, state: &Context<T>, variables: &Context<T>) -> Tensor<T> where T: Copy {
     node.run(state, variables)
 }
 
-pub fn train<T>(node: Arc<Graph<T>>, state: &mut Context<T>, variables: &Context<T>, history: &mut Context<T>, rate: T) where T: Copy {
+/// Returns dC/dState of the cost `node` for every `State` it depends on, keyed by the id of the `State`
+///
+/// `state` is left unchanged, so the gradients can be inspected, clipped or averaged before `apply_gradients`.
+///
+/// # Example
+///
+/// ```
+/// let weight = ktensor::Arc::new(ktensor::State::with_initializer("weight".to_string(), ktensor::Vec2(1, 1), ktensor::init::Constant(2.0)));
+/// let input = ktensor::Arc::new(ktensor::Variable::new("input".to_string(), ktensor::Vec2(1, 1)));
+/// let cost = ktensor::Arc::new(ktensor::op::mul("cost".to_string(), weight.clone(), input.clone()));
+///
+/// let mut state = ktensor::Context::new();
+/// weight.initialize(&mut state);
+/// let mut variables = ktensor::Context::new();
+/// variables.set(input.get_id(), ktensor::Tensor::from_vec(ktensor::Vec2(1, 1), vec![3.0]));
+///
+/// let gradients = ktensor::gradients(cost, &state, &variables);
+/// assert_eq!(gradients.get(weight.get_id()).unwrap().get(ktensor::Vec2(0, 0)), 3.0);
+/// assert!(gradients.get(input.get_id()).is_none());
+/// ```
+pub fn gradients<T>(node: Arc<dyn Graph<T>>, state: &Context<T>, variables: &Context<T>) -> Context<T> where T: Float {
+    let mut history = Context::new();
+    node.train(state, variables, &mut history);
+    backpropagate(&node, &history)
+}
+
+/// Adds `gradients * rate` to the value of each `State` in `state`
+///
+/// Rates are negative to descend the cost.
+pub fn apply_gradients<T>(state: &mut Context<T>, gradients: &Context<T>, rate: T) where T: Float {
+    for (id, gradient) in gradients.iter() {
+        let delta = gradient * &rate;
+        match state.get_mut(id.clone()) {
+            Some(x) => *x += &delta,
+            None    => panic!("State {} does not exist in state", id),
+        }
+    }
+}
+
+/// Trains `node` on one batch, adding `gradients * rate` to the value of each `State`
+///
+/// The gradient of the cost itself is 1, so every `State` moves by `rate * dC/dState`.
+pub fn train<T>(node: Arc<dyn Graph<T>>, state: &mut Context<T>, variables: &Context<T>, history: &mut Context<T>, rate: T) where T: Float {
     node.train(state, variables, history);
-    node.backward_pass(state, variables, history, &Tensor::from_vec(Vec2(1, 1), vec![rate]), rate);
+    let gradients = backpropagate(&node, history);
+    apply_gradients(state, &gradients, rate);
+}
+
+/// Trains `node` on one batch, updating `state` with `optimizer` instead of a fixed rate
+///
+/// # Example
+///
+/// ```
+/// let weight = ktensor::Arc::new(ktensor::State::with_initializer("weight".to_string(), ktensor::Vec2(1, 1), ktensor::init::Constant(2.0)));
+/// let input = ktensor::Arc::new(ktensor::Variable::new("input".to_string(), ktensor::Vec2(1, 1)));
+/// let cost = ktensor::Arc::new(ktensor::op::mul("cost".to_string(), weight.clone(), input.clone()));
+///
+/// let mut state = ktensor::Context::new();
+/// weight.initialize(&mut state);
+/// let mut variables = ktensor::Context::new();
+/// variables.set(input.get_id(), ktensor::Tensor::from_vec(ktensor::Vec2(1, 1), vec![3.0f64]));
+///
+/// let mut optimizer = ktensor::optimizer::Momentum::new(-0.1, 0.9);
+/// ktensor::train_with(cost, &mut state, &variables, &mut ktensor::Context::new(), &mut optimizer);
+/// assert!((state.get(weight.get_id()).unwrap().get(ktensor::Vec2(0, 0)) - 1.7).abs() < 1e-12);
+/// ```
+pub fn train_with<T, O>(node: Arc<dyn Graph<T>>, state: &mut Context<T>, variables: &Context<T>, history: &mut Context<T>, optimizer: &mut O) where T: Float, O: Optimizer<T> + ?Sized {
+    node.train(state, variables, history);
+    let gradients = backpropagate(&node, history);
+    optimizer.step(state, &gradients);
+}
+
+/// Returns the gradients of the states reached from `node`, from the values of a forward pass in `history`
+///
+/// The gradients of a node are summed over all of its consumers before they are propagated,
+/// so every node is visited once and a `State` used several times receives a single gradient.
+fn backpropagate<T>(node: &Arc<dyn Graph<T>>, history: &Context<T>) -> Context<T> where T: Float {
+    let mut gradients = Context::new();
+    let mut state_gradients = Context::new();
+    gradients.set(node.get_id(), Tensor::from_vec(Vec2(1, 1), vec![T::one()]));
+    for current in topological_order(node) {
+        let gradient = match gradients.get(current.get_id()) {
+            Some(x) => x.clone(),
+            None    => continue,
+        };
+        for (delta, parameter) in current.backward_pass(history, &gradient).into_iter().zip(current.get_parameters()) {
+            if let Some(x) = gradients.get_mut(parameter.get_id()) {
+                *x += &delta;
+                continue;
+            }
+            gradients.set(parameter.get_id(), delta);
+        }
+        if current.is_state() {
+            state_gradients.set(current.get_id(), gradient);
+        }
+    }
+    state_gradients
 }
@@ -0,0 +1,159 @@
+use math::{Vec2, Shape, Float};
+use tensor::{Tensor};
+
+/// Sliding window of a 2D convolution over `(height, width)`
+///
+/// # Example
+///
+/// ```
+/// let conv = ktensor::tensor::Convolution {
+///     stride: ktensor::math::Vec2(2, 2),
+///     padding: ktensor::math::Vec2(1, 1),
+///     ..ktensor::tensor::Convolution::new(ktensor::math::Vec2(3, 3))
+/// };
+/// assert_eq!(conv.output_dim(ktensor::math::Vec2(28, 28)), ktensor::math::Vec2(14, 14));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Convolution {
+    /// height and width of the kernel
+    pub kernel: Vec2,
+    /// steps between windows
+    pub stride: Vec2,
+    /// zeros added to each side of the input
+    pub padding: Vec2,
+    /// steps between items of the kernel
+    pub dilation: Vec2,
+}
+
+impl Convolution {
+    /// Returns a `Convolution` of `kernel` with a stride of 1, no padding and no dilation
+    pub fn new(kernel: Vec2) -> Convolution {
+        Convolution {
+            kernel,
+            stride: Vec2(1, 1),
+            padding: Vec2(0, 0),
+            dilation: Vec2(1, 1),
+        }
+    }
+
+    /// Returns the number of windows along `(height, width)` of an input
+    ///
+    /// num_strides = (dim + 2 * padding - dilation * (kernel - 1) - 1) / stride + 1
+    pub fn output_dim(&self, Vec2(height, width): Vec2) -> Vec2 {
+        fn axis(dim: usize, kernel: usize, stride: usize, padding: usize, dilation: usize) -> usize {
+            assert!(kernel > 0 && stride > 0 && dilation > 0, "kernel, stride and dilation must be positive");
+            let span = dilation * (kernel - 1) + 1;
+            assert!(dim + 2 * padding >= span, "kernel spans {} items, but the padded input has {}", span, dim + 2 * padding);
+            (dim + 2 * padding - span) / stride + 1
+        }
+        Vec2(axis(height, self.kernel.0, self.stride.0, self.padding.0, self.dilation.0),
+             axis(width, self.kernel.1, self.stride.1, self.padding.1, self.dilation.1))
+    }
+
+    /// Calls `f(column, input)` for every item of every window that falls inside the input, where `column` is the
+    /// row-major position of the item in the windows and `input` its position in an input of `[batch, channels, height, width]`
+    fn for_each<F>(&self, [batch, channels, height, width]: [usize; 4], mut f: F) where F: FnMut(usize, usize) {
+        let Vec2(out_h, out_w) = self.output_dim(Vec2(height, width));
+        let Vec2(kernel_h, kernel_w) = self.kernel;
+        let cols = channels * kernel_h * kernel_w;
+        for b in 0..batch {
+            for oy in 0..out_h {
+                for ox in 0..out_w {
+                    let row = (b * out_h + oy) * out_w + ox;
+                    for c in 0..channels {
+                        for ky in 0..kernel_h {
+                            let y = (oy * self.stride.0 + ky * self.dilation.0) as isize - self.padding.0 as isize;
+                            if y < 0 || y >= height as isize {
+                                continue;
+                            }
+                            for kx in 0..kernel_w {
+                                let x = (ox * self.stride.1 + kx * self.dilation.1) as isize - self.padding.1 as isize;
+                                if x < 0 || x >= width as isize {
+                                    continue;
+                                }
+                                let col = (c * kernel_h + ky) * kernel_w + kx;
+                                f(row * cols + col, ((b * channels + c) * height + y as usize) * width + x as usize);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Returns the dimensions of `shape` as `[batch, channels, height, width]`, filling missing leading axes with 1
+fn image_dims(shape: &Shape) -> [usize; 4] {
+    let dims = shape.dims();
+    assert!(dims.len() >= 2 && dims.len() <= 4, "expected a shape of rank 2 to 4, got rank {}", dims.len());
+    let mut image = [1; 4];
+    image[4 - dims.len()..].copy_from_slice(dims);
+    image
+}
+
+impl <T> Tensor<T> where T: Float {
+    /// Returns every window of `conv` over the `Tensor` as a row (im2col)
+    ///
+    /// The `Tensor` has the shape `[batch, channels, height, width]`, where missing leading axes are 1.
+    /// The result has a row for each `(batch, out_y, out_x)` and a column for each `(channel, kernel_y, kernel_x)`,
+    /// so a convolution is the product with weights of dimensions `(channels * kernel_h * kernel_w, filters)`.
+    ///
+    /// # Arguments
+    ///
+    /// - `conv` - sliding window
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let image = ktensor::Tensor::from_vec(ktensor::math::Vec2(3, 3), (1..10).map(|i| i as f64).collect());
+    /// let conv = ktensor::tensor::Convolution::new(ktensor::math::Vec2(2, 2));
+    /// let columns = image.get_convolutions(&conv);
+    /// assert_eq!(columns.dim(), ktensor::math::Vec2(4, 4));
+    /// assert_eq!(columns.row(1).to_flattened(), vec![2.0, 3.0, 5.0, 6.0]);
+    /// let kernel = ktensor::Tensor::from_vec(ktensor::math::Vec2(4, 1), vec![1.0; 4]);
+    /// assert_eq!((&columns * &kernel).to_flattened(), vec![12.0, 16.0, 24.0, 28.0]);
+    /// ```
+    pub fn get_convolutions(&self, conv: &Convolution) -> Tensor<T> {
+        let image = image_dims(&self.shape);
+        let [batch, channels, height, width] = image;
+        let Vec2(out_h, out_w) = conv.output_dim(Vec2(height, width));
+        let dim = Vec2(batch * out_h * out_w, channels * conv.kernel.0 * conv.kernel.1);
+        let input: Vec<T> = self.iter().collect();
+        let mut buffer = vec![T::zero(); dim.0 * dim.1];
+        conv.for_each(image, |k, i| buffer[k] = input[i]);
+        Tensor::from_vec(dim, buffer)
+    }
+
+    /// Returns a `Tensor` of `shape` with every row of `columns` added back to the items of its window (col2im)
+    ///
+    /// Items covered by several windows receive the sum of their columns,
+    /// which scatters the gradient of `get_convolutions` back to its input.
+    ///
+    /// # Arguments
+    ///
+    /// - `columns` - one row per window, as returned by `get_convolutions`
+    /// - `conv` - sliding window
+    /// - `shape` - shape of the input of `get_convolutions`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let conv = ktensor::tensor::Convolution::new(ktensor::math::Vec2(2, 2));
+    /// let columns = ktensor::Tensor::from_vec(ktensor::math::Vec2(4, 4), vec![1.0; 16]);
+    /// let counts = ktensor::Tensor::from_convolutions(&columns, &conv, ktensor::math::Vec2(3, 3));
+    /// assert_eq!(counts.to_flattened(), vec![1.0, 2.0, 1.0, 2.0, 4.0, 2.0, 1.0, 2.0, 1.0]);
+    /// ```
+    pub fn from_convolutions<S>(columns: &Tensor<T>, conv: &Convolution, shape: S) -> Tensor<T> where S: Into<Shape> {
+        let shape = shape.into();
+        let image = image_dims(&shape);
+        let [batch, channels, height, width] = image;
+        let Vec2(out_h, out_w) = conv.output_dim(Vec2(height, width));
+        let Vec2(rows, cols) = columns.dim();
+        assert!(rows == batch * out_h * out_w && cols == channels * conv.kernel.0 * conv.kernel.1,
+            "expected columns of dimensions ({}, {}), got ({}, {})", batch * out_h * out_w, channels * conv.kernel.0 * conv.kernel.1, rows, cols);
+        let values: Vec<T> = columns.iter().collect();
+        let mut buffer = vec![T::zero(); shape.len()];
+        conv.for_each(image, |k, i| buffer[i] = buffer[i] + values[k]);
+        Tensor::from_shape(shape, buffer)
+    }
+}
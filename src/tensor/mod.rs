@@ -1,16 +1,20 @@
-extern crate rand;
-use self::rand::{thread_rng};
-use self::rand::distributions::normal::{Normal};
-use self::rand::distributions::{IndependentSample};
+use std::borrow::{Cow};
+use std::cmp;
+use std::fmt;
+use std::ops::{Add, Sub, Mul, Div, Neg, AddAssign, SubAssign, MulAssign, Range};
 
-use std::ops::{Add, Mul};
+use math::{Matrix, Vec2, Shape, Float, Cast, write_grid};
+use error::{KTensorError};
+use random;
 
-use math::{Matrix, Vec2};
+mod convolution;
+
+pub use self::convolution::{Convolution};
 
 /// Encapsulates a matrix for transfer between nodes
 pub struct Tensor<T> {
-    /// vector of the size of the `Tensor`
-    dim: Vec2,
+    /// dimensions of the `Tensor`, viewed by `matrix` as `Shape::to_vec2`
+    shape: Shape,
     /// mutable value of the tensor
     matrix: Matrix<T>,
 }
@@ -32,25 +36,177 @@ impl <T> Tensor<T> {
         assert_eq!(dimensions.0, matrix.dim().0);
         assert_eq!(dimensions.1, matrix.dim().1);
         Tensor {
-            dim: dimensions,
-            matrix: matrix,
+            shape: Shape::from(dimensions),
+            matrix,
         }
     }
 
     pub fn from_vec(dimensions: Vec2, vector: Vec<T>) -> Tensor<T> {
         assert_eq!(dimensions.0 * dimensions.1, vector.len());
         Tensor {
-            dim: dimensions,
+            shape: Shape::from(dimensions),
             matrix: Matrix::new(dimensions, vector),
         }
     }
 
-    /// Returns the dimensions of the `Tensor`
+    /// returns a new `Tensor` of any rank from row-major values
+    ///
+    /// # Arguments
+    ///
+    /// - `shape` - dimensions of the `Tensor`
+    /// - `vector` - `Vec` of values
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let tensor = ktensor::Tensor::from_shape(ktensor::math::Shape::new(vec![2, 3, 4]), (0..24).collect());
+    /// assert_eq!(tensor.shape().rank(), 3);
+    /// assert_eq!(tensor.get_at(&[1, 2, 3]), 23);
+    /// let ktensor::math::Vec2(row, col) = tensor.dim();
+    /// assert_eq!((row, col), (6, 4));
+    /// ```
+    pub fn from_shape<S>(shape: S, vector: Vec<T>) -> Tensor<T> where S: Into<Shape> {
+        let shape = shape.into();
+        assert_eq!(shape.len(), vector.len());
+        Tensor {
+            matrix: Matrix::new(shape.to_vec2(), vector),
+            shape,
+        }
+    }
+
+    /// returns a new `Tensor`, or a `ShapeMismatch` if `dimensions` differ from those of `matrix`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let matrix = ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), (0..6).collect::<Vec<i32>>());
+    /// assert!(ktensor::Tensor::try_new(ktensor::math::Vec2(2, 3), matrix.clone()).is_ok());
+    /// assert!(ktensor::Tensor::try_new(ktensor::math::Vec2(3, 2), matrix).is_err());
+    /// ```
+    pub fn try_new(dimensions: Vec2, matrix: Matrix<T>) -> Result<Tensor<T>, KTensorError> {
+        let Vec2(x, y) = matrix.dim();
+        if dimensions.0 != x || dimensions.1 != y {
+            return Err(KTensorError::shape_mismatch(dimensions, matrix.dim()));
+        }
+        Ok(Tensor::new(dimensions, matrix))
+    }
+
+    /// returns a new `Tensor`, or a `ShapeMismatch` if `vector` does not have one value per item
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// assert!(ktensor::Tensor::try_from_vec(ktensor::math::Vec2(2, 3), vec![0; 6]).is_ok());
+    /// let error = ktensor::Tensor::try_from_vec(ktensor::math::Vec2(2, 3), vec![0; 5]).err().unwrap();
+    /// assert_eq!(error, ktensor::KTensorError::ShapeMismatch { lhs: vec![2, 3], rhs: vec![5] });
+    /// ```
+    pub fn try_from_vec(dimensions: Vec2, vector: Vec<T>) -> Result<Tensor<T>, KTensorError> {
+        Tensor::try_from_shape(dimensions, vector)
+    }
+
+    /// returns a new `Tensor` of any rank, or a `ShapeMismatch` if `vector` does not have one value per item
+    pub fn try_from_shape<S>(shape: S, vector: Vec<T>) -> Result<Tensor<T>, KTensorError> where S: Into<Shape> {
+        let shape = shape.into();
+        if shape.len() != vector.len() {
+            return Err(KTensorError::ShapeMismatch {
+                lhs: shape.dims().to_vec(),
+                rhs: vec![vector.len()],
+            });
+        }
+        Ok(Tensor::from_shape(shape, vector))
+    }
+
+    /// Returns a `Tensor` with `shape` viewing `matrix`
+    fn with_shape(shape: Shape, matrix: Matrix<T>) -> Tensor<T> {
+        Tensor {
+            shape,
+            matrix,
+        }
+    }
+
+    /// Returns the `Shape` of an elementwise result of `self` and `rhs`, or a `ShapeMismatch` if they cannot be broadcast
+    ///
+    /// Shapes are aligned on their last axis and axes of length 1 are broadcast, like NumPy.
+    /// Broadcasts that the `Matrix` views cannot express, such as `[2, 1, 3]` with `[1, 4, 3]`, are also a `ShapeMismatch`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let volume = ktensor::Tensor::from_shape(ktensor::math::Shape::new(vec![2, 3, 1]), vec![0; 6]);
+    /// let matrix = ktensor::Tensor::from_vec(ktensor::math::Vec2(6, 1), vec![0; 6]);
+    /// let row = ktensor::Tensor::from_shape(ktensor::math::Shape::new(vec![4]), vec![0; 4]);
+    /// assert_eq!(volume.broadcast_shape(&row).unwrap().dims(), &[2, 3, 4]);
+    /// assert!(volume.broadcast_shape(&matrix).is_err());
+    /// ```
+    pub fn broadcast_shape<U>(&self, rhs: &Tensor<U>) -> Result<Shape, KTensorError> {
+        let (lhs_dims, rhs_dims) = (self.shape.dims(), rhs.shape.dims());
+        let mismatch = || KTensorError::ShapeMismatch {
+            lhs: lhs_dims.to_vec(),
+            rhs: rhs_dims.to_vec(),
+        };
+        let rank = cmp::max(lhs_dims.len(), rhs_dims.len());
+        let axis = |dims: &[usize], i: usize| if i + dims.len() >= rank { dims[i + dims.len() - rank] } else { 1 };
+        let mut dims = Vec::with_capacity(rank);
+        for i in 0..rank {
+            let (x, y) = (axis(lhs_dims, i), axis(rhs_dims, i));
+            if x != y && x != 1 && y != 1 {
+                return Err(mismatch());
+            }
+            dims.push(if x == 1 { y } else { x });
+        }
+        let shape = Shape::new(dims);
+        match self.dim().broadcast(rhs.dim()) {
+            Some(dim) if dim == shape.to_vec2() => Ok(shape),
+            _                                   => Err(mismatch()),
+        }
+    }
+
+    /// Returns the `Shape` of an elementwise result of `self` and `rhs`, panicking if they cannot be broadcast
+    fn elementwise_shape<U>(&self, rhs: &Tensor<U>) -> Shape {
+        match self.broadcast_shape(rhs) {
+            Ok(shape)  => shape,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Panics unless `rhs` broadcasts to the `Shape` of `self`, for operators assigning in place
+    fn assert_assignable<U>(&self, rhs: &Tensor<U>) {
+        if self.elementwise_shape(rhs) != self.shape {
+            panic!("{}", KTensorError::ShapeMismatch {
+                lhs: self.shape.dims().to_vec(),
+                rhs: rhs.shape.dims().to_vec(),
+            });
+        }
+    }
+
+    /// Returns the dimensions of the `Matrix` view of the `Tensor`
     pub fn dim(&self) -> Vec2 {
-        self.dim
+        self.matrix.dim()
     }
 
-    /// Gives ownership to the `matrix` buffer
+    /// Returns the dimensions of the `Tensor`
+    pub fn shape(&self) -> &Shape {
+        &self.shape
+    }
+
+    /// Returns the `Matrix` view of the `Tensor`
+    pub fn matrix(&self) -> &Matrix<T> {
+        &self.matrix
+    }
+
+    /// Returns the part of the `matrix` buffer spanned by the `Tensor` in memory order
+    pub fn storage(&self) -> &[T] {
+        self.matrix.storage()
+    }
+}
+
+impl <T> Tensor<T> where T: Copy {
+    /// Returns the items of the `Tensor` in row-major order, borrowed unless the `Tensor` is a strided view
+    pub fn buffer(&self) -> Cow<'_, [T]> {
+        self.matrix.buffer()
+    }
+
+    /// Gives ownership to the values of the `Tensor` in row-major order
     ///
     /// # Example
     ///
@@ -66,18 +222,9 @@ impl <T> Tensor<T> {
         self.matrix.to_flattened()
     }
 
-    pub fn buffer(&self) -> &Vec<T> {
-        self.matrix.buffer()
-    }
-
-    /// max_num_strides = (dim - width) / stride + 1
-    pub fn get_convolutions(){
-
-    }
-}
-
-impl <T> Tensor<T> where T: Copy {
-    /// Transpose `Tensor`
+    /// Transpose `Tensor` without copying its buffer
+    ///
+    /// Panics if the `Tensor` has more than 2 axes, which a `Matrix` view cannot permute.
     ///
     /// # Example
     ///
@@ -90,93 +237,948 @@ impl <T> Tensor<T> where T: Copy {
     /// assert_eq!(tensor3[2], 14);
     /// ```
     pub fn transpose(&self) -> Tensor<T> {
-        let Vec2(x, y) = self.dim;
+        assert!(self.shape.rank() <= 2, "cannot transpose a Tensor of rank {}", self.shape.rank());
+        let matrix = self.matrix.transpose();
         Tensor {
-            dim: Vec2(y, x),
-            matrix: self.matrix.transpose(),
+            shape: Shape::from(matrix.dim()),
+            matrix,
         }
     }
 
     pub fn get(&self, vec2: Vec2) -> T {
         self.matrix.get(vec2)
     }
+
+    /// Returns row `i` of the `Matrix` view as a `1 x n` `Tensor` without copying
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let tensor = ktensor::Tensor::from_vec(ktensor::math::Vec2(2, 3), (0..6).collect());
+    /// assert_eq!(tensor.row(1).to_flattened(), vec![3, 4, 5]);
+    /// assert_eq!(tensor.transpose().row(1).to_flattened(), vec![1, 4]);
+    /// ```
+    pub fn row(&self, i: usize) -> Tensor<T> {
+        let matrix = self.matrix.row(i);
+        Tensor::new(matrix.dim(), matrix)
+    }
+
+    /// Returns value at `coords`, with one coordinate per axis of the `Shape`
+    pub fn get_at(&self, coords: &[usize]) -> T {
+        let Vec2(_, col) = self.dim();
+        let offset = self.shape.offset(coords);
+        self.matrix.get(Vec2(offset / col, offset % col))
+    }
+
+    /// Returns the values in row-major order with a new `Shape`
+    ///
+    /// The buffer is shared without copying if it is contiguous, and copied otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// - `shape` - new dimensions with the same number of values
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let images = ktensor::Tensor::from_vec(ktensor::math::Vec2(2, 28 * 28), vec![0.0; 2 * 28 * 28]);
+    /// let rows = images.reshape(ktensor::math::Vec2(2 * 28, 28)).unwrap();
+    /// assert_eq!(rows.shape().dims(), &[56, 28]);
+    /// let volume = images.reshape(ktensor::math::Shape::new(vec![2, 28, 28])).unwrap();
+    /// assert_eq!(volume.shape().rank(), 3);
+    /// assert!(images.reshape(ktensor::math::Vec2(28, 28)).is_err());
+    /// ```
+    pub fn reshape<S>(&self, shape: S) -> Result<Tensor<T>, KTensorError> where S: Into<Shape> {
+        let shape = shape.into();
+        if shape.len() != self.shape.len() {
+            return Err(KTensorError::ShapeMismatch {
+                lhs: self.shape.dims().to_vec(),
+                rhs: shape.dims().to_vec(),
+            });
+        }
+        let matrix = self.matrix.reshape(shape.to_vec2())?;
+        Ok(Tensor::with_shape(shape, matrix))
+    }
+
+    /// Returns the values of each batch in row-major order, keeping axis 0 and merging the trailing axes
+    ///
+    /// A `Tensor` of shape `(batch, d1, ..., dn)` becomes `(batch, d1 * ... * dn)`, so a batch of images can be
+    /// fed to a dense layer. A `Tensor` of rank 1 becomes `(batch, 1)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let images = ktensor::Tensor::from_shape(ktensor::math::Shape::new(vec![2, 2, 3]), (0..12).collect());
+    /// let flat = images.flatten();
+    /// assert_eq!(flat.shape().dims(), &[2, 6]);
+    /// assert_eq!(flat.to_flattened(), (0..12).collect::<Vec<_>>());
+    ///
+    /// let tensor = ktensor::Tensor::from_vec(ktensor::math::Vec2(2, 3), (0..6).collect());
+    /// let flat = tensor.transpose().flatten();
+    /// assert_eq!(flat.shape().dims(), &[3, 2]);
+    /// assert_eq!(flat.to_flattened(), vec![0, 3, 1, 4, 2, 5]);
+    /// ```
+    pub fn flatten(&self) -> Tensor<T> {
+        let dims = self.shape.dims();
+        let batch = dims.first().cloned().unwrap_or(1);
+        let rest = dims.iter().skip(1).product();
+        match self.reshape(Shape::new(vec![batch, rest])) {
+            Ok(tensor) => tensor,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Returns an iterator over the values in row-major order, regardless of the layout of the buffer
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let tensor = ktensor::Tensor::from_vec(ktensor::math::Vec2(2, 3), (0..6).collect());
+    /// let values: Vec<i32> = tensor.transpose().iter().collect();
+    /// assert_eq!(values, vec![0, 3, 1, 4, 2, 5]);
+    /// ```
+    pub fn iter(&self) -> impl ExactSizeIterator<Item=T> + '_ {
+        self.matrix.iter()
+    }
+
+    /// Returns an iterator over the rows of the `Matrix` view as `1 x n` tensors without copying
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let tensor = ktensor::Tensor::from_vec(ktensor::math::Vec2(2, 3), (0..6).collect());
+    /// let sums: Vec<i32> = tensor.rows().map(|row| row.iter().sum()).collect();
+    /// assert_eq!(sums, vec![3, 12]);
+    /// ```
+    pub fn rows(&self) -> impl ExactSizeIterator<Item=Tensor<T>> + '_ {
+        self.matrix.rows().map(|matrix| Tensor::new(matrix.dim(), matrix))
+    }
+
+    /// Returns an iterator over the columns of the `Matrix` view as `n x 1` tensors without copying
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let tensor = ktensor::Tensor::from_vec(ktensor::math::Vec2(2, 3), (0..6).collect());
+    /// let sums: Vec<i32> = tensor.cols().map(|col| col.iter().sum()).collect();
+    /// assert_eq!(sums, vec![3, 5, 7]);
+    /// ```
+    pub fn cols(&self) -> impl ExactSizeIterator<Item=Tensor<T>> + '_ {
+        self.matrix.cols().map(|matrix| Tensor::new(matrix.dim(), matrix))
+    }
+}
+
+impl <T> Tensor<T> where T: Copy + Send + Sync {
+    /// Returns a `Tensor` of the same `Shape` with `f` applied to every value
+    ///
+    /// # Arguments
+    ///
+    /// - `f` - function of a value
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let tensor = ktensor::Tensor::from_shape(ktensor::math::Shape::new(vec![2, 1, 3]), (0..6).collect());
+    /// let tensor = tensor.map(|a| a as f64 / 2.0);
+    /// assert_eq!(tensor.shape().rank(), 3);
+    /// assert_eq!(tensor.get_at(&[1, 0, 2]), 2.5);
+    /// ```
+    pub fn map<U, F>(&self, f: F) -> Tensor<U> where U: Send, F: Fn(T) -> U + Sync {
+        Tensor::with_shape(self.shape.clone(), self.matrix.map(f))
+    }
+
+    /// Returns a `Tensor` of `f` applied to pairs of values of broadcast tensors
+    ///
+    /// # Arguments
+    ///
+    /// - `rhs` - another tensor
+    /// - `f` - function of a value of `self` and a value of `rhs`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let tensor1 = ktensor::Tensor::from_vec(ktensor::math::Vec2(2, 3), (0..6).collect());
+    /// let tensor2 = ktensor::Tensor::from_vec(ktensor::math::Vec2(1, 3), vec![2, 0, 2]);
+    /// let tensor3 = tensor1.zip_map(&tensor2, |a, b| a > b);
+    /// assert_eq!(tensor3.to_flattened(), vec![false, true, false, true, true, true]);
+    /// ```
+    pub fn zip_map<U, V, F>(&self, rhs: &Tensor<U>, f: F) -> Tensor<V> where U: Copy + Send + Sync, V: Send, F: Fn(T, U) -> V + Sync {
+        let shape = self.elementwise_shape(rhs);
+        Tensor::with_shape(shape, self.matrix.zip_map(&rhs.matrix, f))
+    }
 }
 
-impl <T> Clone for Tensor<T> where T: Copy {
+impl <T> Clone for Tensor<T> {
     fn clone(&self) -> Tensor<T> {
         Tensor {
-            dim: self.dim,
+            shape: self.shape.clone(),
             matrix: self.matrix.clone(),
         }
     }
 }
 
-impl Tensor<f64> {
-    /// generates a random valued `Tensor` with a standard deviation of 1
+impl <T> Tensor<T> where T: Float {
+    /// generates a random valued `Tensor` from a normal distribution with a variance of `2 / input_dim`
     ///
     /// # Arguments
     ///
     /// - `dimensions` - dimensions of `Tensor`
+    /// - `input_dim` - number of inputs feeding into the `Tensor`
     ///
     /// # Example
     ///
     /// ```
     /// let tensor = ktensor::Tensor::<f64>::from_gaussian(ktensor::math::Vec2(5, 5), 2);
+    /// let tensor = ktensor::Tensor::<f32>::from_gaussian(ktensor::math::Vec2(5, 5), 2);
     /// ```
-    pub fn from_gaussian(dimensions: Vec2, input_dim: usize) -> Tensor<f64> {
+    pub fn from_gaussian(dimensions: Vec2, input_dim: usize) -> Tensor<T> {
         let Vec2(row, col) = dimensions;
-        let mut rng = thread_rng();
-        let normal = Normal::new(0.0, (2.0 / input_dim as f64).sqrt());
-        let mut buf = Vec::<f64>::with_capacity(row * col);
-        for _ in 0..row * col {
-            buf.push(normal.ind_sample(&mut rng));
+        let buf = random::normal(row * col, 0.0, (2.0 / input_dim as f64).sqrt()).into_iter().map(T::from_f64).collect();
+        Tensor::from_vec(dimensions, buf)
+    }
+}
+
+
+////////////////
+// Reduction  //
+////////////////
+
+/// Returns the number of lanes before `axis`, the length of `axis` and the number of items after it, for row-major `dims`
+fn lanes(dims: &[usize], axis: usize) -> (usize, usize, usize) {
+    assert!(axis < dims.len(), "axis {} is out of range for a tensor of rank {}", axis, dims.len());
+    (dims[..axis].iter().product(), dims[axis], dims[axis + 1..].iter().product())
+}
+
+impl <T> Tensor<T> where T: Copy {
+    /// Applies `f` to the items of every lane along `axis`, keeping the reduced axis with a length of 1
+    ///
+    /// Tensors of rank 2 or less reduce their `Matrix` view, tensors of higher rank reduce the axis of their `Shape`.
+    ///
+    /// # Arguments
+    ///
+    /// - `axis` - axis to reduce, `None` reduces every item
+    /// - `f` - reduces the items of a lane
+    fn reduce<U, F>(&self, axis: Option<usize>, f: F) -> Tensor<U> where F: Fn(&[T]) -> U {
+        if self.shape.rank() > 2 {
+            return self.reduce_shape(axis, f);
         }
+        let Vec2(row, col) = self.dim();
+        let (dim, lanes, len) = match axis {
+            Some(0) => (Vec2(1, col), col, row),
+            Some(1) => (Vec2(row, 1), row, col),
+            None    => (Vec2(1, 1), 1, row * col),
+            Some(x) => panic!("axis {} is out of range for a `Matrix`", x),
+        };
 
-        Tensor {
-            dim: dimensions,
-            matrix: Matrix::new(dimensions, buf),
+        let mut lane = Vec::with_capacity(len);
+        let mut buffer = Vec::with_capacity(lanes);
+        for l in 0..lanes {
+            lane.clear();
+            match axis {
+                Some(0) => lane.extend(self.matrix.col(l).iter()),
+                Some(_) => lane.extend(self.matrix.row(l).iter()),
+                None    => lane.extend(self.matrix.iter()),
+            }
+            buffer.push(f(&lane));
+        }
+        Tensor::from_vec(dim, buffer)
+    }
+
+    /// Applies `f` to the items of every lane along `axis` of the `Shape`, keeping the reduced axis with a length of 1
+    fn reduce_shape<U, F>(&self, axis: Option<usize>, f: F) -> Tensor<U> where F: Fn(&[T]) -> U {
+        let items: Vec<T> = self.iter().collect();
+        let mut dims = self.shape.dims().to_vec();
+        let axis = match axis {
+            Some(axis) => axis,
+            None       => {
+                let buffer = vec![f(&items)];
+                return Tensor::from_shape(Shape::new(vec![1; dims.len()]), buffer);
+            },
+        };
+        let (outer, len, inner) = lanes(&dims, axis);
+        let mut lane = Vec::with_capacity(len);
+        let mut buffer = Vec::with_capacity(outer * inner);
+        for o in 0..outer {
+            for i in 0..inner {
+                lane.clear();
+                lane.extend((0..len).map(|k| items[(o * len + k) * inner + i]));
+                buffer.push(f(&lane));
+            }
         }
+        dims[axis] = 1;
+        Tensor::from_shape(Shape::new(dims), buffer)
     }
 }
 
-impl Tensor<f32> {
-    /// generates a random valued matrix with a standard deviation of 1
+impl <T> Tensor<T> where T: Float {
+    /// Sums items along `axis`
     ///
+    /// # Arguments
+    ///
+    /// - `axis` - `Some(0)` sums each column, `Some(1)` sums each row, `None` sums every item,
+    ///   and tensors of higher rank sum along their axis `axis`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let tensor = ktensor::Tensor::from_vec(ktensor::math::Vec2(2, 3), vec![1.0, 5.0, 3.0, 4.0, 2.0, 6.0]);
+    /// assert_eq!(tensor.sum(Some(0)).to_flattened(), vec![5.0, 7.0, 9.0]);
+    /// assert_eq!(tensor.sum(Some(1)).to_flattened(), vec![9.0, 12.0]);
+    /// assert_eq!(tensor.sum(None).to_flattened(), vec![21.0]);
+    ///
+    /// let volume = ktensor::Tensor::from_shape(ktensor::math::Shape::new(vec![2, 3, 4]), (0..24).map(|x| x as f64).collect());
+    /// let sum = volume.sum(Some(0));
+    /// assert_eq!(sum.shape().dims(), &[1, 3, 4]);
+    /// assert_eq!(sum.to_flattened(), (0..12).map(|x| 2.0 * x as f64 + 12.0).collect::<Vec<_>>());
+    /// let sum = volume.sum(Some(1));
+    /// assert_eq!(sum.shape().dims(), &[2, 1, 4]);
+    /// assert_eq!(&sum.to_flattened()[..4], &[12.0, 15.0, 18.0, 21.0]);
+    /// assert_eq!(volume.sum(None).shape().dims(), &[1, 1, 1]);
+    /// ```
+    pub fn sum(&self, axis: Option<usize>) -> Tensor<T> {
+        self.reduce(axis, |lane| lane.iter().fold(T::zero(), |sum, &a| sum + a))
+    }
+
+    /// Averages items along `axis`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let tensor = ktensor::Tensor::from_vec(ktensor::math::Vec2(2, 3), vec![1.0, 5.0, 3.0, 4.0, 2.0, 6.0]);
+    /// assert_eq!(tensor.mean(Some(0)).to_flattened(), vec![2.5, 3.5, 4.5]);
+    /// assert_eq!(tensor.mean(None).to_flattened(), vec![3.5]);
+    /// ```
+    pub fn mean(&self, axis: Option<usize>) -> Tensor<T> {
+        self.reduce(axis, |lane| lane.iter().fold(T::zero(), |sum, &a| sum + a) / T::from_usize(lane.len()))
+    }
+
+    /// Returns the largest item along `axis`, ignoring NaN
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let tensor = ktensor::Tensor::from_vec(ktensor::math::Vec2(2, 3), vec![1.0, 5.0, 3.0, 4.0, 2.0, 6.0]);
+    /// assert_eq!(tensor.max(Some(1)).to_flattened(), vec![5.0, 6.0]);
+    /// ```
+    pub fn max(&self, axis: Option<usize>) -> Tensor<T> {
+        self.reduce(axis, |lane| lane.iter().fold(T::nan(), |m, &a| m.max(a)))
+    }
+
+    /// Returns the smallest item along `axis`, ignoring NaN
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let tensor = ktensor::Tensor::from_vec(ktensor::math::Vec2(2, 3), vec![1.0, 5.0, 3.0, 4.0, 2.0, 6.0]);
+    /// assert_eq!(tensor.min(Some(0)).to_flattened(), vec![1.0, 2.0, 3.0]);
+    /// ```
+    pub fn min(&self, axis: Option<usize>) -> Tensor<T> {
+        self.reduce(axis, |lane| lane.iter().fold(T::nan(), |m, &a| m.min(a)))
+    }
+
+    /// Returns the index of the first largest item along `axis`, ignoring NaN
+    ///
+    /// With `axis` of `None`, the index is into the items in row-major order.
+    /// A lane of only NaN gives 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let tensor = ktensor::Tensor::from_vec(ktensor::math::Vec2(2, 3), vec![1.0, 5.0, 3.0, 4.0, 2.0, 6.0]);
+    /// assert_eq!(tensor.argmax(Some(1)).to_flattened(), vec![1, 2]);
+    /// assert_eq!(tensor.argmax(Some(0)).to_flattened(), vec![1, 0, 1]);
+    /// assert_eq!(tensor.argmax(None).to_flattened(), vec![5]);
+    ///
+    /// let tensor = ktensor::Tensor::from_vec(ktensor::math::Vec2(2, 3), vec![std::f64::NAN, 1.0, 2.0, std::f64::NAN, std::f64::NAN, std::f64::NAN]);
+    /// assert_eq!(tensor.argmax(Some(1)).to_flattened(), vec![2, 0]);
+    /// ```
+    pub fn argmax(&self, axis: Option<usize>) -> Tensor<usize> {
+        self.reduce(axis, |lane| {
+            assert!(!lane.is_empty(), "cannot take the argmax of an empty axis");
+            // NaN is never greater than another item, so starting from the first number skips every NaN
+            let mut index = lane.iter().position(|a| !a.is_nan()).unwrap_or(0);
+            for (i, &a) in lane.iter().enumerate().skip(index + 1) {
+                if a > lane[index] {
+                    index = i;
+                }
+            }
+            index
+        })
+    }
+
+    /// Returns the population variance of items along `axis`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let tensor = ktensor::Tensor::from_vec(ktensor::math::Vec2(2, 3), vec![1.0, 5.0, 3.0, 4.0, 2.0, 6.0]);
+    /// assert_eq!(tensor.var(Some(0)).to_flattened(), vec![2.25, 2.25, 2.25]);
+    /// ```
+    pub fn var(&self, axis: Option<usize>) -> Tensor<T> {
+        self.reduce(axis, |lane| {
+            let n = T::from_usize(lane.len());
+            let mean = lane.iter().fold(T::zero(), |sum, &a| sum + a) / n;
+            lane.iter().fold(T::zero(), |sum, &a| sum + (a - mean) * (a - mean)) / n
+        })
+    }
+
+    /// Returns the euclidean norm of items along `axis`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let tensor = ktensor::Tensor::from_vec(ktensor::math::Vec2(2, 2), vec![3.0, 0.0, 4.0, 1.0]);
+    /// assert_eq!(tensor.norm(Some(0)).to_flattened(), vec![5.0, 1.0]);
+    /// ```
+    pub fn norm(&self, axis: Option<usize>) -> Tensor<T> {
+        self.reduce(axis, |lane| lane.iter().fold(T::zero(), |sum, &a| sum + a * a).sqrt())
+    }
+}
+
+////////////////////////////
+// Joining and Splitting  //
+////////////////////////////
+
+impl <T> Tensor<T> where T: Copy {
+    /// Returns rows `rows` and columns `cols` of the `Matrix` view without copying
+    ///
+    /// Panics for tensors of rank above 2, whose axes are not the rows and columns of the view.
     ///
     /// # Arguments
     ///
-    /// - `dimensions` - dimensions of `Tensor`
+    /// - `rows` - range of rows
+    /// - `cols` - range of columns
     ///
     /// # Example
     ///
     /// ```
-    /// let tensor = ktensor::Tensor::<f32>::from_gaussian(ktensor::math::Vec2(5, 5), 2);
+    /// let tensor = ktensor::Tensor::from_vec(ktensor::math::Vec2(3, 3), (0..9).collect());
+    /// assert_eq!(tensor.slice(1..3, 0..2).to_flattened(), vec![3, 4, 6, 7]);
+    /// assert_eq!(tensor.transpose().slice(0..1, 1..3).to_flattened(), vec![3, 6]);
+    /// ```
+    pub fn slice(&self, rows: Range<usize>, cols: Range<usize>) -> Tensor<T> {
+        assert!(self.shape.rank() <= 2, "cannot slice rows and columns of a tensor of rank {}", self.shape.rank());
+        let Vec2(x, y) = self.dim();
+        assert!(rows.start <= rows.end && rows.end <= x, "rows {:?} are out of range for {} rows", rows, x);
+        assert!(cols.start <= cols.end && cols.end <= y, "columns {:?} are out of range for {} columns", cols, y);
+        let dim = Vec2(rows.end - rows.start, cols.end - cols.start);
+        if dim.0 == 0 || dim.1 == 0 {
+            return Tensor::from_vec(dim, Vec::new());
+        }
+        let matrix = self.matrix.get_submatrix(Vec2(rows.start, cols.start), Vec2(rows.end, cols.end));
+        Tensor::new(dim, matrix)
+    }
+
+    /// Splits the tensor along `axis` into pieces of `sizes`
+    ///
+    /// Tensors of rank 2 or less split their `Matrix` view without copying, tensors of higher rank split the axis
+    /// of their `Shape` into copies.
+    ///
+    /// # Arguments
+    ///
+    /// - `sizes` - length of each piece along `axis`, adding up to the length of `axis`
+    /// - `axis` - 0 to split rows, 1 to split columns, or an axis of the `Shape` of a tensor of higher rank
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let tensor = ktensor::Tensor::from_vec(ktensor::math::Vec2(2, 5), (0..10).collect());
+    /// let heads = tensor.split(&[2, 3], 1);
+    /// assert_eq!(heads[0].iter().collect::<Vec<i32>>(), vec![0, 1, 5, 6]);
+    /// assert_eq!(heads[1].iter().collect::<Vec<i32>>(), vec![2, 3, 4, 7, 8, 9]);
+    ///
+    /// let volume = ktensor::Tensor::from_shape(ktensor::math::Shape::new(vec![2, 3, 4]), (0..24).collect());
+    /// let pieces = volume.split(&[1, 2], 1);
+    /// assert_eq!(pieces[0].shape().dims(), &[2, 1, 4]);
+    /// assert_eq!(pieces[0].iter().collect::<Vec<i32>>(), vec![0, 1, 2, 3, 12, 13, 14, 15]);
+    /// assert_eq!(pieces[1].shape().dims(), &[2, 2, 4]);
+    /// ```
+    pub fn split(&self, sizes: &[usize], axis: usize) -> Vec<Tensor<T>> {
+        if self.shape.rank() > 2 {
+            return self.split_shape(sizes, axis);
+        }
+        let Vec2(x, y) = self.dim();
+        let len = match axis {
+            0 => x,
+            1 => y,
+            _ => panic!("axis {} is out of range for a `Matrix`", axis),
+        };
+        assert_eq!(sizes.iter().sum::<usize>(), len, "sizes {:?} do not add up to {}", sizes, len);
+        let mut start = 0;
+        sizes.iter().map(|&size| {
+            let range = start..start + size;
+            start += size;
+            match axis {
+                0 => self.slice(range, 0..y),
+                _ => self.slice(0..x, range),
+            }
+        }).collect()
+    }
+
+    /// Splits the axis `axis` of the `Shape` into copies of lengths `sizes`
+    fn split_shape(&self, sizes: &[usize], axis: usize) -> Vec<Tensor<T>> {
+        let dims = self.shape.dims();
+        let (outer, len, inner) = lanes(dims, axis);
+        assert_eq!(sizes.iter().sum::<usize>(), len, "sizes {:?} do not add up to {}", sizes, len);
+        let items: Vec<T> = self.iter().collect();
+        let mut start = 0;
+        sizes.iter().map(|&size| {
+            let mut buffer = Vec::with_capacity(outer * size * inner);
+            for o in 0..outer {
+                buffer.extend_from_slice(&items[(o * len + start) * inner..(o * len + start + size) * inner]);
+            }
+            start += size;
+            let mut dims = dims.to_vec();
+            dims[axis] = size;
+            Tensor::from_shape(Shape::new(dims), buffer)
+        }).collect()
+    }
+
+    /// Joins `tensors` along `axis` into a single buffer
+    ///
+    /// Tensors of rank 2 or less join their `Matrix` views, tensors of higher rank join the axis of their `Shape`,
+    /// and must have the same rank and the same lengths on every other axis.
+    ///
+    /// # Arguments
+    ///
+    /// - `tensors` - tensors with equal columns to join rows, or equal rows to join columns
+    /// - `axis` - 0 to join rows, 1 to join columns, or an axis of the `Shape` of tensors of higher rank
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let tensor1 = ktensor::Tensor::from_vec(ktensor::math::Vec2(1, 2), vec![0, 1]);
+    /// let tensor2 = ktensor::Tensor::from_vec(ktensor::math::Vec2(2, 2), vec![2, 3, 4, 5]);
+    /// assert_eq!(ktensor::Tensor::concat(&[tensor1.clone(), tensor2.clone()], 0).to_flattened(), vec![0, 1, 2, 3, 4, 5]);
+    /// let tensor3 = ktensor::Tensor::concat(&[tensor2.clone(), tensor2.transpose()], 1);
+    /// assert_eq!(tensor3.to_flattened(), vec![2, 3, 2, 4, 4, 5, 3, 5]);
+    ///
+    /// let volume1 = ktensor::Tensor::from_shape(ktensor::math::Shape::new(vec![2, 1, 2]), vec![0, 1, 4, 5]);
+    /// let volume2 = ktensor::Tensor::from_shape(ktensor::math::Shape::new(vec![2, 1, 2]), vec![2, 3, 6, 7]);
+    /// let volume = ktensor::Tensor::concat(&[volume1, volume2], 1);
+    /// assert_eq!(volume.shape().dims(), &[2, 2, 2]);
+    /// assert_eq!(volume.to_flattened(), (0..8).collect::<Vec<_>>());
+    /// ```
+    pub fn concat(tensors: &[Tensor<T>], axis: usize) -> Tensor<T> {
+        assert!(!tensors.is_empty(), "cannot concatenate 0 tensors");
+        if tensors.iter().any(|tensor| tensor.shape.rank() > 2) {
+            return Tensor::concat_shape(tensors, axis);
+        }
+        let Vec2(x, y) = tensors[0].dim();
+        let dim = match axis {
+            0 => {
+                assert!(tensors.iter().all(|tensor| tensor.dim().1 == y), "cannot concatenate rows of tensors with different columns");
+                Vec2(tensors.iter().map(|tensor| tensor.dim().0).sum(), y)
+            },
+            1 => {
+                assert!(tensors.iter().all(|tensor| tensor.dim().0 == x), "cannot concatenate columns of tensors with different rows");
+                Vec2(x, tensors.iter().map(|tensor| tensor.dim().1).sum())
+            },
+            _ => panic!("axis {} is out of range for a `Matrix`", axis),
+        };
+
+        let mut buffer = Vec::with_capacity(dim.0 * dim.1);
+        if axis == 0 {
+            for tensor in tensors {
+                if tensor.matrix.is_contiguous() {
+                    buffer.extend_from_slice(tensor.storage());
+                } else {
+                    buffer.extend(tensor.iter());
+                }
+            }
+        } else {
+            for i in 0..x {
+                for tensor in tensors {
+                    buffer.extend(tensor.matrix.row(i).iter());
+                }
+            }
+        }
+        Tensor::from_vec(dim, buffer)
+    }
+
+    /// Joins the axis `axis` of the `Shape` of `tensors`
+    fn concat_shape(tensors: &[Tensor<T>], axis: usize) -> Tensor<T> {
+        let dims = tensors[0].shape.dims();
+        let (outer, _, inner) = lanes(dims, axis);
+        assert!(tensors.iter().all(|tensor| {
+            let other = tensor.shape.dims();
+            other.len() == dims.len() && (0..dims.len()).all(|i| i == axis || other[i] == dims[i])
+        }), "cannot concatenate tensors of different ranks or lengths outside axis {}", axis);
+
+        let items: Vec<Vec<T>> = tensors.iter().map(|tensor| tensor.iter().collect()).collect();
+        let len: usize = tensors.iter().map(|tensor| tensor.shape.dims()[axis]).sum();
+        let mut buffer = Vec::with_capacity(outer * len * inner);
+        for o in 0..outer {
+            for (tensor, items) in tensors.iter().zip(&items) {
+                let run = tensor.shape.dims()[axis] * inner;
+                buffer.extend_from_slice(&items[o * run..(o + 1) * run]);
+            }
+        }
+        let mut dims = dims.to_vec();
+        dims[axis] = len;
+        Tensor::from_shape(Shape::new(dims), buffer)
+    }
+
+    /// Joins `tensors` of the same `Shape` along a new leading axis
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let tensor1 = ktensor::Tensor::from_vec(ktensor::math::Vec2(2, 2), vec![0, 1, 2, 3]);
+    /// let tensor2 = ktensor::Tensor::from_vec(ktensor::math::Vec2(2, 2), vec![4, 5, 6, 7]);
+    /// let tensor3 = ktensor::Tensor::stack(&[tensor1, tensor2]);
+    /// assert_eq!(tensor3.shape().dims(), &[2, 2, 2]);
+    /// assert_eq!(tensor3.get_at(&[1, 0, 1]), 5);
+    /// ```
+    pub fn stack(tensors: &[Tensor<T>]) -> Tensor<T> {
+        assert!(!tensors.is_empty(), "cannot stack 0 tensors");
+        let shape = &tensors[0].shape;
+        assert!(tensors.iter().all(|tensor| tensor.shape == *shape), "cannot stack tensors of different shapes");
+        let mut dims = vec![tensors.len()];
+        dims.extend_from_slice(shape.dims());
+        let tensor = Tensor::concat(tensors, 0);
+        Tensor::from_shape(Shape::new(dims), tensor.to_flattened())
+    }
+}
+
+/////////////////
+// Conversion  //
+/////////////////
+
+impl <T> Tensor<T> where T: Copy + Send + Sync {
+    /// Returns a `Tensor` of the same `Shape` with every value converted to `U`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let tensor = ktensor::Tensor::from_vec(ktensor::math::Vec2(1, 3), vec![-1.5f64, 0.0, 2.7]);
+    /// assert_eq!(tensor.cast::<i32>().to_flattened(), vec![-1, 0, 2]);
+    /// assert_eq!(tensor.cast::<bool>().to_flattened(), vec![true, false, true]);
+    /// ```
+    pub fn cast<U>(&self) -> Tensor<U> where T: Cast<U>, U: Send {
+        self.map(|a| a.cast())
+    }
+}
+
+/////////////////
+// Comparison  //
+/////////////////
+
+impl <T> Tensor<T> where T: PartialOrd + Copy + Send + Sync {
+    /// Returns `true` where `self` is greater than `rhs`, broadcasting axes of length 1
+    ///
+    /// # Arguments
+    ///
+    /// - `rhs` - another tensor
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let tensor1 = ktensor::Tensor::from_vec(ktensor::math::Vec2(2, 2), vec![1, 5, 3, 2]);
+    /// let tensor2 = ktensor::Tensor::from_vec(ktensor::math::Vec2(1, 2), vec![3, 2]);
+    /// assert_eq!(tensor1.gt_elem(&tensor2).to_flattened(), vec![false, true, false, false]);
+    /// ```
+    pub fn gt_elem(&self, rhs: &Tensor<T>) -> Tensor<bool> {
+        self.zip_map(rhs, |a, b| a > b)
+    }
+
+    /// Returns `true` where `self` is greater than or equal to `rhs`, broadcasting axes of length 1
+    ///
+    /// # Arguments
+    ///
+    /// - `rhs` - another tensor
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let tensor1 = ktensor::Tensor::from_vec(ktensor::math::Vec2(2, 2), vec![1, 5, 3, 2]);
+    /// let tensor2 = ktensor::Tensor::from_vec(ktensor::math::Vec2(1, 2), vec![3, 2]);
+    /// assert_eq!(tensor1.ge_elem(&tensor2).to_flattened(), vec![false, true, true, true]);
+    /// ```
+    pub fn ge_elem(&self, rhs: &Tensor<T>) -> Tensor<bool> {
+        self.zip_map(rhs, |a, b| a >= b)
+    }
+
+    /// Returns `true` where `self` is less than `rhs`, broadcasting axes of length 1
+    ///
+    /// # Arguments
+    ///
+    /// - `rhs` - another tensor
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let tensor1 = ktensor::Tensor::from_vec(ktensor::math::Vec2(2, 2), vec![1, 5, 3, 2]);
+    /// let tensor2 = ktensor::Tensor::from_vec(ktensor::math::Vec2(1, 2), vec![3, 2]);
+    /// assert_eq!(tensor1.lt_elem(&tensor2).to_flattened(), vec![true, false, false, false]);
+    /// ```
+    pub fn lt_elem(&self, rhs: &Tensor<T>) -> Tensor<bool> {
+        self.zip_map(rhs, |a, b| a < b)
+    }
+
+    /// Returns `true` where `self` is less than or equal to `rhs`, broadcasting axes of length 1
+    ///
+    /// # Arguments
+    ///
+    /// - `rhs` - another tensor
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let tensor1 = ktensor::Tensor::from_vec(ktensor::math::Vec2(2, 2), vec![1, 5, 3, 2]);
+    /// let tensor2 = ktensor::Tensor::from_vec(ktensor::math::Vec2(1, 2), vec![3, 2]);
+    /// assert_eq!(tensor1.le_elem(&tensor2).to_flattened(), vec![true, false, true, true]);
+    /// ```
+    pub fn le_elem(&self, rhs: &Tensor<T>) -> Tensor<bool> {
+        self.zip_map(rhs, |a, b| a <= b)
+    }
+
+    /// Returns `true` where `self` is equal to `rhs`, broadcasting axes of length 1
+    ///
+    /// # Arguments
+    ///
+    /// - `rhs` - another tensor
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let tensor1 = ktensor::Tensor::from_vec(ktensor::math::Vec2(2, 2), vec![1, 5, 3, 2]);
+    /// let tensor2 = ktensor::Tensor::from_vec(ktensor::math::Vec2(1, 2), vec![3, 2]);
+    /// assert_eq!(tensor1.eq_elem(&tensor2).to_flattened(), vec![false, false, true, true]);
+    /// assert!(tensor1 != tensor2);
+    /// ```
+    pub fn eq_elem(&self, rhs: &Tensor<T>) -> Tensor<bool> {
+        self.zip_map(rhs, |a, b| a == b)
+    }
+
+    /// Returns `true` where `self` is not equal to `rhs`, broadcasting axes of length 1
+    ///
+    /// # Arguments
+    ///
+    /// - `rhs` - another tensor
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let tensor1 = ktensor::Tensor::from_vec(ktensor::math::Vec2(2, 2), vec![1, 5, 3, 2]);
+    /// let tensor2 = ktensor::Tensor::from_vec(ktensor::math::Vec2(1, 2), vec![3, 2]);
+    /// assert_eq!(tensor1.ne_elem(&tensor2).to_flattened(), vec![true, true, false, false]);
+    /// ```
+    pub fn ne_elem(&self, rhs: &Tensor<T>) -> Tensor<bool> {
+        self.zip_map(rhs, |a, b| a != b)
+    }
+}
+
+impl <T> Tensor<T> where T: Copy + Send + Sync {
+    /// Returns values of `a` where `mask` is `true` and values of `b` elsewhere, broadcasting axes of length 1
+    ///
+    /// # Arguments
+    ///
+    /// - `mask` - tensor of conditions
+    /// - `a` - values where `mask` is `true`
+    /// - `b` - values where `mask` is `false`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let tensor = ktensor::Tensor::from_vec(ktensor::math::Vec2(2, 2), vec![-1.0, 5.0, 3.0, -2.0]);
+    /// let zero = ktensor::Tensor::from_vec(ktensor::math::Vec2(1, 1), vec![0.0]);
+    /// let relu = ktensor::Tensor::select(&tensor.gt_elem(&zero), &tensor, &zero);
+    /// assert_eq!(relu.to_flattened(), vec![0.0, 5.0, 3.0, 0.0]);
+    /// ```
+    pub fn select(mask: &Tensor<bool>, a: &Tensor<T>, b: &Tensor<T>) -> Tensor<T> {
+        mask.zip_map(a, |m, a| (m, a)).zip_map(b, |(m, a), b| if m { a } else { b })
+    }
+}
+
+/////////////////
+// Formatting  //
+/////////////////
+
+impl <T> fmt::Display for Tensor<T> where T: Copy + fmt::Display {
+    /// Formats the rows of the `Matrix` view like NumPy, summarizing large tensors
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let tensor = ktensor::Tensor::from_vec(ktensor::math::Vec2(2, 2), vec![1.0, 2.0, 3.0, 4.0]);
+    /// assert_eq!(format!("{:.2}", tensor), "[[1.00, 2.00],\n [3.00, 4.00]]");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.matrix, f)
+    }
+}
+
+impl <T> fmt::Debug for Tensor<T> where T: Copy + fmt::Debug {
+    /// Formats the rows of the `Matrix` view and the `Shape` like a NumPy repr
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let tensor = ktensor::Tensor::from_vec(ktensor::math::Vec2(2, 2), vec![1.0, 2.0, 3.0, 4.0]);
+    /// assert_eq!(format!("{:?}", tensor), "Tensor([[1.0, 2.0],\n        [3.0, 4.0]], shape=[2, 2])");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Tensor(")?;
+        write_grid(f, &self.matrix, 7, |x, precision| match precision {
+            Some(p) => format!("{:.*?}", p, x),
+            None    => format!("{:?}", x),
+        })?;
+        write!(f, ", shape={:?})", self.shape.dims())
+    }
+}
+
+impl <T> PartialEq for Tensor<T> where T: PartialEq + Copy {
+    /// Tensors are equal if they have the same `Shape` and values, regardless of layout
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let tensor = ktensor::Tensor::from_vec(ktensor::math::Vec2(2, 2), vec![1.0, 2.0, 3.0, 4.0]);
+    /// assert!(tensor == tensor.transpose().transpose());
+    /// assert!(tensor != tensor.transpose());
+    /// ```
+    fn eq(&self, other: &Tensor<T>) -> bool {
+        self.shape == other.shape && self.matrix == other.matrix
+    }
+}
+
+impl <T> Tensor<T> where T: Float {
+    /// Returns `true` if the tensors have the same `Shape` and every pair of values differ by at most `tolerance`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let tensor1 = ktensor::Tensor::from_vec(ktensor::math::Vec2(2, 2), vec![1.0, 2.0, 3.0, 4.0]);
+    /// let tensor2 = &tensor1 * &1.0000001;
+    /// assert!(tensor1.approx_eq(&tensor2, 1e-6));
+    /// assert!(!tensor1.approx_eq(&tensor2.transpose(), 1e-6));
+    /// ```
+    pub fn approx_eq(&self, other: &Tensor<T>, tolerance: T) -> bool {
+        self.shape == other.shape && self.matrix.approx_eq(&other.matrix, tolerance)
+    }
+}
+
+//////////////
+// Addition //
+//////////////
+
+impl <T> Add<Tensor<T>> for Tensor<T> where T: Add<Output=T> + Copy + Send + Sync {
+    type Output = Tensor<T>;
+
+    /// Add `Tensor`s
+    ///
+    /// # Arguments
+    ///
+    /// - `self` - this tensor
+    /// - `rhs` - another tensor
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let tensor1 = ktensor::Tensor::new(ktensor::math::Vec2(2, 3), ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), (0..6).map(|i| i as f64).collect()));
+    /// let tensor2 = ktensor::Tensor::new(ktensor::math::Vec2(2, 3), ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), (0..6).map(|i| i as f64).rev().collect()));
+    /// let tensor3 = tensor1 + tensor2;
+    /// let tensor3 = tensor3.to_flattened();
+    /// assert_eq!(tensor3[0], 5.0);
+    /// ```
+    fn add(self, rhs: Tensor<T>) -> Tensor<T> {
+        &self + &rhs
+    }
+}
+
+impl <T> Add<&Tensor<T>> for &Tensor<T> where T: Add<Output=T> + Copy + Send + Sync {
+    type Output = Tensor<T>;
+
+    /// Add `Tensor`s by reference, broadcasting axes of length 1
+    ///
+    /// # Arguments
+    ///
+    /// - `self` - this tensor reference
+    /// - `rhs` - another tensor reference
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let tensor1 = ktensor::Tensor::new(ktensor::math::Vec2(2, 3), ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), (0..6).map(|i| i as f64).collect()));
+    /// let tensor2 = ktensor::Tensor::new(ktensor::math::Vec2(2, 3), ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), (0..6).map(|i| i as f64).rev().collect()));
+    /// let tensor3 = &tensor1 + &tensor2;
+    /// let tensor1 = tensor1.to_flattened();
+    /// let tensor2 = tensor2.to_flattened();
+    /// let tensor3 = tensor3.to_flattened();
+    /// assert_eq!(tensor1[0], 0.0);
+    /// assert_eq!(tensor2[0], 5.0);
+    /// assert_eq!(tensor3[0], 5.0);
+    /// let column = ktensor::Tensor::from_vec(ktensor::math::Vec2(2, 1), vec![1.0, 2.0]);
+    /// let row = ktensor::Tensor::from_vec(ktensor::math::Vec2(1, 3), vec![0.0, 10.0, 20.0]);
+    /// let tensor4 = (&column + &row).to_flattened();
+    /// assert_eq!(tensor4, vec![1.0, 11.0, 21.0, 2.0, 12.0, 22.0]);
+    /// ```
+    fn add(self, rhs: &Tensor<T>) -> Tensor<T> {
+        Tensor::with_shape(self.elementwise_shape(rhs), &self.matrix + &rhs.matrix)
+    }
+}
+
+impl <T> Add<T> for Tensor<T> where T: Add<Output=T> + Copy + Send + Sync {
+    type Output = Tensor<T>;
+
+    /// Add `Tensor` and a constant
+    ///
+    /// # Arguments
+    ///
+    /// - `self` - this `Tensor`
+    /// - `rhs` - a constant
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let tensor1 = ktensor::Tensor::new(ktensor::math::Vec2(2, 3), ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), (0..6).map(|i| i as f64).collect()));
+    /// let float = 1.0;
+    /// let tensor2 = tensor1 + float;
+    /// let tensor2 = tensor2.to_flattened();
+    /// assert_eq!(tensor2[0], 1.0);
+    /// ```
+    fn add(self, rhs: T) -> Tensor<T> {
+        &self + &rhs
+    }
+}
+
+impl <T> Add<&T> for &Tensor<T> where T: Add<Output=T> + Copy + Send + Sync {
+    type Output = Tensor<T>;
+
+    /// Add `Tensor` and a constant
+    ///
+    /// # Arguments
+    ///
+    /// - `self` - this tensor reference
+    /// - `rhs` - a constant reference
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let tensor1 = ktensor::Tensor::new(ktensor::math::Vec2(2, 3), ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), (0..6).map(|i| i as f64).collect()));
+    /// let float = 1.0;
+    /// let tensor2 = &tensor1 + &float;
+    /// let tensor1 = tensor1.to_flattened();
+    /// let tensor2 = tensor2.to_flattened();
+    /// assert_eq!(tensor1[0], 0.0);
+    /// assert_eq!(tensor2[0], 1.0);
+    /// assert_eq!(float, 1.0);
     /// ```
-    pub fn from_gaussian(dimensions: Vec2, input_dim: usize) -> Tensor<f32> {
-        let Vec2(row, col) = dimensions;
-        let mut rng = thread_rng();
-        let normal = Normal::new(0.0, (2.0 / input_dim as f64).sqrt());
-        let mut buf = Vec::<f32>::with_capacity(row * col);
-        for _ in 0..row * col {
-            buf.push(normal.ind_sample(&mut rng) as f32);
-        }
-
-        Tensor {
-            dim: dimensions,
-            matrix: Matrix::new(dimensions, buf),
-        }
+    fn add(self, rhs: &T) -> Tensor<T> {
+        Tensor::with_shape(self.shape.clone(), &self.matrix + rhs)
     }
 }
 
 
-//////////////
-// Addition //
-//////////////
+//////////////////
+// Subtraction  //
+//////////////////
 
-impl <T> Add<Tensor<T>> for Tensor<T> where T: Add<Output=T> + Copy {
+impl <T> Sub<Tensor<T>> for Tensor<T> where T: Sub<Output=T> + Copy + Send + Sync {
     type Output = Tensor<T>;
 
-    /// Add `Tensor`s
+    /// Subtract `Tensor`s elementwise
     ///
     /// # Arguments
     ///
@@ -186,21 +1188,21 @@ impl <T> Add<Tensor<T>> for Tensor<T> where T: Add<Output=T> + Copy {
     /// # Example
     ///
     /// ```
-    /// let tensor1 = ktensor::Tensor::new(ktensor::math::Vec2(2, 3), ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), (0..6).map(|i| i as f64).collect()));
-    /// let tensor2 = ktensor::Tensor::new(ktensor::math::Vec2(2, 3), ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), (0..6).map(|i| i as f64).rev().collect()));
-    /// let tensor3 = tensor1 + tensor2;
+    /// let tensor1 = ktensor::Tensor::from_vec(ktensor::math::Vec2(2, 3), (0..6).map(|i| i as f64).collect());
+    /// let tensor2 = ktensor::Tensor::from_vec(ktensor::math::Vec2(2, 3), (1..7).map(|i| i as f64).rev().collect());
+    /// let tensor3 = tensor1 - tensor2;
     /// let tensor3 = tensor3.to_flattened();
-    /// assert_eq!(tensor3[0], 5.0);
+    /// assert_eq!(tensor3[2], -2.0);
     /// ```
-    fn add(self, rhs: Tensor<T>) -> Tensor<T> {
-        Tensor::new(self.dim, self.matrix + rhs.matrix)
+    fn sub(self, rhs: Tensor<T>) -> Tensor<T> {
+        &self - &rhs
     }
 }
 
-impl <'a, 'b, T> Add<&'b Tensor<T>> for &'a Tensor<T> where T: Add<Output=T> + Copy {
+impl <T> Sub<&Tensor<T>> for &Tensor<T> where T: Sub<Output=T> + Copy + Send + Sync {
     type Output = Tensor<T>;
 
-    /// Add `Tensor`s by reference
+    /// Subtract `Tensor`s elementwise by reference, broadcasting axes of length 1
     ///
     /// # Arguments
     ///
@@ -210,49 +1212,49 @@ impl <'a, 'b, T> Add<&'b Tensor<T>> for &'a Tensor<T> where T: Add<Output=T> + C
     /// # Example
     ///
     /// ```
-    /// let tensor1 = ktensor::Tensor::new(ktensor::math::Vec2(2, 3), ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), (0..6).map(|i| i as f64).collect()));
-    /// let tensor2 = ktensor::Tensor::new(ktensor::math::Vec2(2, 3), ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), (0..6).map(|i| i as f64).rev().collect()));
-    /// let tensor3 = &tensor1 + &tensor2;
+    /// let tensor1 = ktensor::Tensor::from_vec(ktensor::math::Vec2(2, 3), (0..6).map(|i| i as f64).collect());
+    /// let tensor2 = ktensor::Tensor::from_vec(ktensor::math::Vec2(2, 3), (1..7).map(|i| i as f64).rev().collect());
+    /// let tensor3 = &tensor1 - &tensor2;
     /// let tensor1 = tensor1.to_flattened();
     /// let tensor2 = tensor2.to_flattened();
     /// let tensor3 = tensor3.to_flattened();
-    /// assert_eq!(tensor1[0], 0.0);
-    /// assert_eq!(tensor2[0], 5.0);
-    /// assert_eq!(tensor3[0], 5.0);
+    /// assert_eq!(tensor1[2], 2.0);
+    /// assert_eq!(tensor2[2], 4.0);
+    /// assert_eq!(tensor3[2], -2.0);
     /// ```
-    fn add(self, rhs: &'b Tensor<T>) -> Tensor<T> {
-        Tensor::new(self.dim, &self.matrix + &rhs.matrix)
+    fn sub(self, rhs: &Tensor<T>) -> Tensor<T> {
+        Tensor::with_shape(self.elementwise_shape(rhs), &self.matrix - &rhs.matrix)
     }
 }
 
-impl <T> Add<T> for Tensor<T> where T: Add<Output=T> + Copy {
+impl <T> Sub<T> for Tensor<T> where T: Sub<Output=T> + Copy + Send + Sync {
     type Output = Tensor<T>;
 
-    /// Add `Tensor` and a constant
+    /// Subtract `Tensor` and a constant
     ///
     /// # Arguments
     ///
-    /// - `self` - this `Tensor`
+    /// - `self` - this tensor
     /// - `rhs` - a constant
     ///
     /// # Example
     ///
     /// ```
-    /// let tensor1 = ktensor::Tensor::new(ktensor::math::Vec2(2, 3), ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), (0..6).map(|i| i as f64).collect()));
-    /// let float = 1.0;
-    /// let tensor2 = tensor1 + float;
+    /// let tensor1 = ktensor::Tensor::from_vec(ktensor::math::Vec2(2, 3), (0..6).map(|i| i as f64).collect());
+    /// let float = 2.0;
+    /// let tensor2 = tensor1 - float;
     /// let tensor2 = tensor2.to_flattened();
-    /// assert_eq!(tensor2[0], 1.0);
+    /// assert_eq!(tensor2[2], 0.0);
     /// ```
-    fn add(self, rhs: T) -> Tensor<T> {
-        Tensor::new(self.dim, self.matrix + rhs)
+    fn sub(self, rhs: T) -> Tensor<T> {
+        &self - &rhs
     }
 }
 
-impl <'a, 'b, T> Add<&'b T> for &'a Tensor<T> where T: Add<Output=T> + Copy {
+impl <T> Sub<&T> for &Tensor<T> where T: Sub<Output=T> + Copy + Send + Sync {
     type Output = Tensor<T>;
 
-    /// Add `Tensor` and a constant
+    /// Subtract `Tensor` and a constant
     ///
     /// # Arguments
     ///
@@ -262,17 +1264,17 @@ impl <'a, 'b, T> Add<&'b T> for &'a Tensor<T> where T: Add<Output=T> + Copy {
     /// # Example
     ///
     /// ```
-    /// let tensor1 = ktensor::Tensor::new(ktensor::math::Vec2(2, 3), ktensor::math::Matrix::new(ktensor::math::Vec2(2, 3), (0..6).map(|i| i as f64).collect()));
-    /// let float = 1.0;
-    /// let tensor2 = &tensor1 + &float;
+    /// let tensor1 = ktensor::Tensor::from_vec(ktensor::math::Vec2(2, 3), (0..6).map(|i| i as f64).collect());
+    /// let float = 2.0;
+    /// let tensor2 = &tensor1 - &float;
     /// let tensor1 = tensor1.to_flattened();
     /// let tensor2 = tensor2.to_flattened();
-    /// assert_eq!(tensor1[0], 0.0);
-    /// assert_eq!(tensor2[0], 1.0);
-    /// assert_eq!(float, 1.0);
+    /// assert_eq!(tensor1[2], 2.0);
+    /// assert_eq!(tensor2[2], 0.0);
+    /// assert_eq!(float, 2.0);
     /// ```
-    fn add(self, rhs: &'b T) -> Tensor<T> {
-        Tensor::new(self.dim, &self.matrix + rhs)
+    fn sub(self, rhs: &T) -> Tensor<T> {
+        Tensor::with_shape(self.shape.clone(), &self.matrix - rhs)
     }
 }
 
@@ -281,7 +1283,7 @@ impl <'a, 'b, T> Add<&'b T> for &'a Tensor<T> where T: Add<Output=T> + Copy {
 // Multiplication  //
 /////////////////////
 
-impl <T> Mul<Tensor<T>> for Tensor<T> where T: Mul<Output=T> + Add<Output=T> + Copy {
+impl <T> Mul<Tensor<T>> for Tensor<T> where T: Mul<Output=T> + Add<Output=T> + Copy + Default + Send + Sync {
     type Output = Tensor<T>;
 
     /// Multiply `Tensor`s`
@@ -302,11 +1304,11 @@ impl <T> Mul<Tensor<T>> for Tensor<T> where T: Mul<Output=T> + Add<Output=T> + C
     /// assert_eq!(tensor3[0], 5.0);
     /// ```
     fn mul(self, rhs: Tensor<T>) -> Tensor<T> {
-        Tensor::new(Vec2(self.dim.0, rhs.dim.1), self.matrix * rhs.matrix)
+        &self * &rhs
     }
 }
 
-impl <'a, 'b, T> Mul<&'b Tensor<T>> for &'a Tensor<T> where T: Mul<Output=T> + Add<Output=T> + Copy {
+impl <T> Mul<&Tensor<T>> for &Tensor<T> where T: Mul<Output=T> + Add<Output=T> + Copy + Default + Send + Sync {
     type Output = Tensor<T>;
 
     /// Multiply `Tensor`s` by reference
@@ -330,12 +1332,13 @@ impl <'a, 'b, T> Mul<&'b Tensor<T>> for &'a Tensor<T> where T: Mul<Output=T> + A
     /// assert_eq!(tensor2[0], 5.0);
     /// assert_eq!(tensor3[0], 5.0);
     /// ```
-    fn mul(self, rhs: &'b Tensor<T>) -> Tensor<T> {
-        Tensor::new(Vec2(self.dim.0, rhs.dim.1), &self.matrix * &rhs.matrix)
+    fn mul(self, rhs: &Tensor<T>) -> Tensor<T> {
+        let matrix = &self.matrix * &rhs.matrix;
+        Tensor::new(matrix.dim(), matrix)
     }
 }
 
-impl <T> Mul<T> for Tensor<T> where T: Mul<Output=T> + Copy {
+impl <T> Mul<T> for Tensor<T> where T: Mul<Output=T> + Copy + Send + Sync {
     type Output = Tensor<T>;
 
     /// Multiply `Tensor` and a constant
@@ -355,11 +1358,11 @@ impl <T> Mul<T> for Tensor<T> where T: Mul<Output=T> + Copy {
     /// assert_eq!(tensor2[2], 4.0);
     /// ```
     fn mul(self, rhs: T) -> Tensor<T> {
-        Tensor::new(self.dim, self.matrix * rhs)
+        &self * &rhs
     }
 }
 
-impl <'a, 'b, T> Mul<&'b T> for &'a Tensor<T> where T: Mul<Output=T> + Copy {
+impl <T> Mul<&T> for &Tensor<T> where T: Mul<Output=T> + Copy + Send + Sync {
     type Output = Tensor<T>;
 
     /// Multiply `Tensor` and a constant
@@ -381,8 +1384,8 @@ impl <'a, 'b, T> Mul<&'b T> for &'a Tensor<T> where T: Mul<Output=T> + Copy {
     /// assert_eq!(tensor2[2], 4.0);
     /// assert_eq!(float, 2.0);
     /// ```
-    fn mul(self, rhs: &'b T) -> Tensor<T> {
-        Tensor::new(self.dim, &self.matrix * rhs)
+    fn mul(self, rhs: &T) -> Tensor<T> {
+        Tensor::with_shape(self.shape.clone(), &self.matrix * rhs)
     }
 }
 
@@ -391,8 +1394,8 @@ impl <'a, 'b, T> Mul<&'b T> for &'a Tensor<T> where T: Mul<Output=T> + Copy {
 // Hadamard Product  //
 ///////////////////////
 
-impl <'a, T> Tensor<T> where T: Mul<Output=T> + Copy {
-    /// Hadamard Product of Tensors by reference
+impl <T> Tensor<T> where T: Mul<Output=T> + Copy + Send + Sync {
+    /// Hadamard Product of Tensors by reference, broadcasting axes of length 1
     ///
     /// # Arguments
     ///
@@ -412,7 +1415,377 @@ impl <'a, T> Tensor<T> where T: Mul<Output=T> + Copy {
     /// assert_eq!(tensor2[2], 3.0);
     /// assert_eq!(tensor3[2], 6.0);
     /// ```
-    pub fn product(&self, rhs: &'a Tensor<T>) -> Tensor<T> {
-        Tensor::new(self.dim, self.matrix.product(&rhs.matrix))
+    pub fn product(&self, rhs: &Tensor<T>) -> Tensor<T> {
+        Tensor::with_shape(self.elementwise_shape(rhs), self.matrix.product(&rhs.matrix))
+    }
+}
+
+
+///////////////
+// Division  //
+///////////////
+
+impl <T> Div<Tensor<T>> for Tensor<T> where T: Div<Output=T> + Copy + Send + Sync {
+    type Output = Tensor<T>;
+
+    /// Divide `Tensor`s elementwise
+    ///
+    /// # Arguments
+    ///
+    /// - `self` - this tensor
+    /// - `rhs` - another tensor
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let tensor1 = ktensor::Tensor::from_vec(ktensor::math::Vec2(2, 3), (0..6).map(|i| i as f64).collect());
+    /// let tensor2 = ktensor::Tensor::from_vec(ktensor::math::Vec2(2, 3), (1..7).map(|i| i as f64).rev().collect());
+    /// let tensor3 = tensor1 / tensor2;
+    /// let tensor3 = tensor3.to_flattened();
+    /// assert_eq!(tensor3[2], 0.5);
+    /// ```
+    fn div(self, rhs: Tensor<T>) -> Tensor<T> {
+        &self / &rhs
+    }
+}
+
+impl <T> Div<&Tensor<T>> for &Tensor<T> where T: Div<Output=T> + Copy + Send + Sync {
+    type Output = Tensor<T>;
+
+    /// Divide `Tensor`s elementwise by reference, broadcasting axes of length 1
+    ///
+    /// # Arguments
+    ///
+    /// - `self` - this tensor reference
+    /// - `rhs` - another tensor reference
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let tensor1 = ktensor::Tensor::from_vec(ktensor::math::Vec2(2, 3), (0..6).map(|i| i as f64).collect());
+    /// let tensor2 = ktensor::Tensor::from_vec(ktensor::math::Vec2(2, 3), (1..7).map(|i| i as f64).rev().collect());
+    /// let tensor3 = &tensor1 / &tensor2;
+    /// let tensor1 = tensor1.to_flattened();
+    /// let tensor2 = tensor2.to_flattened();
+    /// let tensor3 = tensor3.to_flattened();
+    /// assert_eq!(tensor1[2], 2.0);
+    /// assert_eq!(tensor2[2], 4.0);
+    /// assert_eq!(tensor3[2], 0.5);
+    /// ```
+    fn div(self, rhs: &Tensor<T>) -> Tensor<T> {
+        Tensor::with_shape(self.elementwise_shape(rhs), &self.matrix / &rhs.matrix)
+    }
+}
+
+impl <T> Div<T> for Tensor<T> where T: Div<Output=T> + Copy + Send + Sync {
+    type Output = Tensor<T>;
+
+    /// Divide `Tensor` and a constant
+    ///
+    /// # Arguments
+    ///
+    /// - `self` - this tensor
+    /// - `rhs` - a constant
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let tensor1 = ktensor::Tensor::from_vec(ktensor::math::Vec2(2, 3), (0..6).map(|i| i as f64).collect());
+    /// let float = 2.0;
+    /// let tensor2 = tensor1 / float;
+    /// let tensor2 = tensor2.to_flattened();
+    /// assert_eq!(tensor2[2], 1.0);
+    /// ```
+    fn div(self, rhs: T) -> Tensor<T> {
+        &self / &rhs
+    }
+}
+
+impl <T> Div<&T> for &Tensor<T> where T: Div<Output=T> + Copy + Send + Sync {
+    type Output = Tensor<T>;
+
+    /// Divide `Tensor` and a constant
+    ///
+    /// # Arguments
+    ///
+    /// - `self` - this tensor reference
+    /// - `rhs` - a constant reference
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let tensor1 = ktensor::Tensor::from_vec(ktensor::math::Vec2(2, 3), (0..6).map(|i| i as f64).collect());
+    /// let float = 2.0;
+    /// let tensor2 = &tensor1 / &float;
+    /// let tensor1 = tensor1.to_flattened();
+    /// let tensor2 = tensor2.to_flattened();
+    /// assert_eq!(tensor1[2], 2.0);
+    /// assert_eq!(tensor2[2], 1.0);
+    /// assert_eq!(float, 2.0);
+    /// ```
+    fn div(self, rhs: &T) -> Tensor<T> {
+        Tensor::with_shape(self.shape.clone(), &self.matrix / rhs)
+    }
+}
+
+
+///////////////
+// Negation  //
+///////////////
+
+impl <T> Neg for Tensor<T> where T: Neg<Output=T> + Copy + Send + Sync {
+    type Output = Tensor<T>;
+
+    /// Negate `Tensor`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let tensor1 = ktensor::Tensor::from_vec(ktensor::math::Vec2(2, 3), (0..6).map(|i| i as f64).collect());
+    /// let tensor2 = -tensor1;
+    /// let tensor2 = tensor2.to_flattened();
+    /// assert_eq!(tensor2[2], -2.0);
+    /// ```
+    fn neg(self) -> Tensor<T> {
+        -&self
+    }
+}
+
+impl <T> Neg for &Tensor<T> where T: Neg<Output=T> + Copy + Send + Sync {
+    type Output = Tensor<T>;
+
+    /// Negate `Tensor` by reference
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let tensor1 = ktensor::Tensor::from_vec(ktensor::math::Vec2(2, 3), (0..6).map(|i| i as f64).collect());
+    /// let tensor2 = -&tensor1;
+    /// let tensor1 = tensor1.to_flattened();
+    /// let tensor2 = tensor2.to_flattened();
+    /// assert_eq!(tensor1[2], 2.0);
+    /// assert_eq!(tensor2[2], -2.0);
+    /// ```
+    fn neg(self) -> Tensor<T> {
+        Tensor::with_shape(self.shape.clone(), -&self.matrix)
+    }
+}
+
+
+/////////////////
+// Assignment  //
+/////////////////
+
+impl <T> AddAssign<Tensor<T>> for Tensor<T> where T: Add<Output=T> + Copy + Send + Sync {
+    /// Add a `Tensor` in place, broadcasting `rhs` to the dimensions of `self`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut tensor1 = ktensor::Tensor::from_vec(ktensor::math::Vec2(2, 3), (0..6).map(|i| i as f64).collect());
+    /// let tensor2 = ktensor::Tensor::from_vec(ktensor::math::Vec2(2, 3), (1..7).map(|i| i as f64).rev().collect());
+    /// tensor1 += tensor2;
+    /// let tensor1 = tensor1.to_flattened();
+    /// assert_eq!(tensor1[2], 6.0);
+    /// ```
+    fn add_assign(&mut self, rhs: Tensor<T>) {
+        self.assert_assignable(&rhs);
+        self.matrix += &rhs.matrix;
+    }
+}
+
+impl <T> AddAssign<&Tensor<T>> for Tensor<T> where T: Add<Output=T> + Copy + Send + Sync {
+    /// Add a `Tensor` reference in place, broadcasting `rhs` to the dimensions of `self`
+    ///
+    /// The buffer is reused unless it is shared with another `Tensor`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut tensor1 = ktensor::Tensor::from_vec(ktensor::math::Vec2(2, 3), (0..6).map(|i| i as f64).collect());
+    /// let bias = ktensor::Tensor::from_vec(ktensor::math::Vec2(1, 3), vec![1.0, 1.0, 1.0]);
+    /// tensor1 += &bias;
+    /// let tensor1 = tensor1.to_flattened();
+    /// assert_eq!(tensor1[5], 5.0 + 1.0);
+    /// ```
+    fn add_assign(&mut self, rhs: &Tensor<T>) {
+        self.assert_assignable(rhs);
+        self.matrix += &rhs.matrix;
+    }
+}
+
+impl <T> AddAssign<T> for Tensor<T> where T: Add<Output=T> + Copy + Send + Sync {
+    /// Add a constant in place
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut tensor1 = ktensor::Tensor::from_vec(ktensor::math::Vec2(2, 3), (0..6).map(|i| i as f64).collect());
+    /// tensor1 += 2.0;
+    /// let tensor1 = tensor1.to_flattened();
+    /// assert_eq!(tensor1[2], 4.0);
+    /// ```
+    fn add_assign(&mut self, rhs: T) {
+        self.matrix += rhs;
+    }
+}
+
+impl <T> SubAssign<Tensor<T>> for Tensor<T> where T: Sub<Output=T> + Copy + Send + Sync {
+    /// Subtract a `Tensor` in place, broadcasting `rhs` to the dimensions of `self`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut tensor1 = ktensor::Tensor::from_vec(ktensor::math::Vec2(2, 3), (0..6).map(|i| i as f64).collect());
+    /// let tensor2 = ktensor::Tensor::from_vec(ktensor::math::Vec2(2, 3), (1..7).map(|i| i as f64).rev().collect());
+    /// tensor1 -= tensor2;
+    /// let tensor1 = tensor1.to_flattened();
+    /// assert_eq!(tensor1[2], -2.0);
+    /// ```
+    fn sub_assign(&mut self, rhs: Tensor<T>) {
+        self.assert_assignable(&rhs);
+        self.matrix -= &rhs.matrix;
+    }
+}
+
+impl <T> SubAssign<&Tensor<T>> for Tensor<T> where T: Sub<Output=T> + Copy + Send + Sync {
+    /// Subtract a `Tensor` reference in place, broadcasting `rhs` to the dimensions of `self`
+    ///
+    /// The buffer is reused unless it is shared with another `Tensor`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut tensor1 = ktensor::Tensor::from_vec(ktensor::math::Vec2(2, 3), (0..6).map(|i| i as f64).collect());
+    /// let bias = ktensor::Tensor::from_vec(ktensor::math::Vec2(1, 3), vec![1.0, 1.0, 1.0]);
+    /// tensor1 -= &bias;
+    /// let tensor1 = tensor1.to_flattened();
+    /// assert_eq!(tensor1[5], 5.0 - 1.0);
+    /// ```
+    fn sub_assign(&mut self, rhs: &Tensor<T>) {
+        self.assert_assignable(rhs);
+        self.matrix -= &rhs.matrix;
+    }
+}
+
+impl <T> SubAssign<T> for Tensor<T> where T: Sub<Output=T> + Copy + Send + Sync {
+    /// Subtract a constant in place
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut tensor1 = ktensor::Tensor::from_vec(ktensor::math::Vec2(2, 3), (0..6).map(|i| i as f64).collect());
+    /// tensor1 -= 2.0;
+    /// let tensor1 = tensor1.to_flattened();
+    /// assert_eq!(tensor1[2], 0.0);
+    /// ```
+    fn sub_assign(&mut self, rhs: T) {
+        self.matrix -= rhs;
+    }
+}
+
+impl <T> MulAssign<T> for Tensor<T> where T: Mul<Output=T> + Copy + Send + Sync {
+    /// Multiply by a constant in place
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let mut tensor1 = ktensor::Tensor::from_vec(ktensor::math::Vec2(2, 3), (0..6).map(|i| i as f64).collect());
+    /// tensor1 *= 2.0;
+    /// let tensor1 = tensor1.to_flattened();
+    /// assert_eq!(tensor1[2], 4.0);
+    /// ```
+    fn mul_assign(&mut self, rhs: T) {
+        self.matrix *= rhs;
+    }
+}
+
+impl <T> Tensor<T> where T: Add<Output=T> + Copy {
+    /// Sums items along the axes broadcast from `dimensions`
+    ///
+    /// Reduces the gradient of a broadcast operand back to the dimensions of the operand.
+    ///
+    /// # Arguments
+    ///
+    /// - `dimensions` - dimensions to reduce to, each axis is either kept or 1
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let tensor = ktensor::Tensor::from_vec(ktensor::math::Vec2(2, 3), (0..6).collect());
+    /// let tensor = tensor.sum_to(ktensor::math::Vec2(1, 3));
+    /// assert_eq!(tensor.to_flattened(), vec![3, 5, 7]);
+    /// ```
+    pub fn sum_to(&self, dimensions: Vec2) -> Tensor<T> {
+        let Vec2(x, y) = self.dim();
+        if x == dimensions.0 && y == dimensions.1 {
+            return self.clone();
+        }
+        Tensor::new(dimensions, self.matrix.sum_to(dimensions))
+    }
+}
+
+/////////////////////////
+// Checked Arithmetic  //
+/////////////////////////
+
+impl <T> Tensor<T> where T: Add<Output=T> + Copy + Send + Sync {
+    /// Add `Tensor`s by reference, or return a `ShapeMismatch` if they cannot be broadcast
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let tensor1 = ktensor::Tensor::from_vec(ktensor::math::Vec2(2, 3), (0..6).collect::<Vec<i32>>());
+    /// let tensor2 = ktensor::Tensor::from_vec(ktensor::math::Vec2(3, 2), (0..6).collect::<Vec<i32>>());
+    /// assert!(tensor1.try_add(&tensor1).is_ok());
+    /// assert!(tensor1.try_add(&tensor2).is_err());
+    /// let volume = ktensor::Tensor::from_shape(ktensor::math::Shape::new(vec![2, 3, 1]), (0..6).collect::<Vec<i32>>());
+    /// let column = ktensor::Tensor::from_vec(ktensor::math::Vec2(6, 1), (0..6).collect::<Vec<i32>>());
+    /// assert!(volume.try_add(&column).is_err());
+    /// ```
+    pub fn try_add(&self, rhs: &Tensor<T>) -> Result<Tensor<T>, KTensorError> {
+        let shape = self.broadcast_shape(rhs)?;
+        Ok(Tensor::with_shape(shape, self.matrix.try_add(&rhs.matrix)?))
+    }
+}
+
+impl <T> Tensor<T> where T: Sub<Output=T> + Copy + Send + Sync {
+    /// Subtract `Tensor`s elementwise by reference, or return a `ShapeMismatch` if they cannot be broadcast
+    pub fn try_sub(&self, rhs: &Tensor<T>) -> Result<Tensor<T>, KTensorError> {
+        let shape = self.broadcast_shape(rhs)?;
+        Ok(Tensor::with_shape(shape, self.matrix.try_sub(&rhs.matrix)?))
+    }
+}
+
+impl <T> Tensor<T> where T: Mul<Output=T> + Add<Output=T> + Copy + Default + Send + Sync {
+    /// Multiply `Tensor`s by reference, or return a `ShapeMismatch` if the shared dimensions differ
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let tensor1 = ktensor::Tensor::from_vec(ktensor::math::Vec2(2, 3), (0..6).collect::<Vec<i32>>());
+    /// let tensor2 = ktensor::Tensor::from_vec(ktensor::math::Vec2(3, 2), (0..6).collect::<Vec<i32>>());
+    /// assert!(tensor1.try_mul(&tensor2).is_ok());
+    /// assert!(tensor1.try_mul(&tensor1).is_err());
+    /// ```
+    pub fn try_mul(&self, rhs: &Tensor<T>) -> Result<Tensor<T>, KTensorError> {
+        let matrix = self.matrix.try_mul(&rhs.matrix)?;
+        Ok(Tensor::new(matrix.dim(), matrix))
+    }
+}
+
+impl <T> Tensor<T> where T: Mul<Output=T> + Copy + Send + Sync {
+    /// Hadamard Product of `Tensor`s by reference, or return a `ShapeMismatch` if they cannot be broadcast
+    pub fn try_product(&self, rhs: &Tensor<T>) -> Result<Tensor<T>, KTensorError> {
+        let shape = self.broadcast_shape(rhs)?;
+        Ok(Tensor::with_shape(shape, self.matrix.try_product(&rhs.matrix)?))
+    }
+}
+
+impl <T> Tensor<T> where T: Div<Output=T> + Copy + Send + Sync {
+    /// Divide `Tensor`s elementwise by reference, or return a `ShapeMismatch` if they cannot be broadcast
+    pub fn try_div(&self, rhs: &Tensor<T>) -> Result<Tensor<T>, KTensorError> {
+        let shape = self.broadcast_shape(rhs)?;
+        Ok(Tensor::with_shape(shape, self.matrix.try_div(&rhs.matrix)?))
     }
 }
@@ -0,0 +1,77 @@
+extern crate ktensor as k;
+use k::math::{Matrix, Vec2};
+
+/// Deterministic pseudo random items, so failures can be reproduced
+fn random_matrix(dim: Vec2, seed: u64) -> Matrix<i64> {
+    let mut x = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407) | 1;
+    Matrix::new(dim, (0..dim.0 * dim.1).map(|_| {
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        (x % 19) as i64 - 9
+    }).collect())
+}
+
+/// Textbook triple loop product
+fn naive_product(a: &Matrix<i64>, b: &Matrix<i64>) -> Matrix<i64> {
+    let Vec2(rows, depth) = a.dim();
+    let cols = b.dim().1;
+    let mut buffer = vec![0; rows * cols];
+    for i in 0..rows {
+        for j in 0..cols {
+            for l in 0..depth {
+                buffer[i * cols + j] += a.get(Vec2(i, l)) * b.get(Vec2(l, j));
+            }
+        }
+    }
+    Matrix::new(Vec2(rows, cols), buffer)
+}
+
+#[test]
+fn blocked_product_matches_naive() {
+    // sizes around the 64 x 256 x 256 blocks, and vectors
+    let mut seed = 0;
+    for &rows in [1, 2, 63, 64, 65, 130].iter() {
+        for &depth in [1, 2, 7, 255, 256, 257].iter() {
+            for &cols in [1, 3, 255, 256, 257].iter() {
+                seed += 1;
+                let a = random_matrix(Vec2(rows, depth), seed);
+                let b = random_matrix(Vec2(depth, cols), seed + 1000);
+                assert_eq!(&a * &b, naive_product(&a, &b), "({}, {}) x ({}, {})", rows, depth, depth, cols);
+            }
+        }
+    }
+}
+
+#[test]
+fn blocked_product_of_views_matches_naive() {
+    let a = random_matrix(Vec2(80, 300), 1);
+    let b = random_matrix(Vec2(300, 90), 2);
+
+    // transposed operands
+    let a_t = random_matrix(Vec2(300, 80), 3).transpose();
+    let b_t = random_matrix(Vec2(90, 300), 4).transpose();
+    assert_eq!(&a_t * &b_t, naive_product(&a_t, &b_t));
+    assert_eq!(&a * &b_t, naive_product(&a, &b_t));
+
+    // strided submatricies and a transposed submatrix
+    let a_sub = a.get_submatrix(Vec2(3, 5), Vec2(70, 290));
+    let b_sub = b.get_submatrix(Vec2(5, 1), Vec2(290, 89));
+    assert_eq!(&a_sub * &b_sub, naive_product(&a_sub, &b_sub));
+    let b_sub_t = random_matrix(Vec2(100, 300), 7).get_submatrix(Vec2(5, 5), Vec2(90, 290)).transpose();
+    assert_eq!(&a_sub * &b_sub_t, naive_product(&a_sub, &b_sub_t));
+
+    // broadcast rows and a broadcast item, which have strides of 0
+    let row = random_matrix(Vec2(1, 300), 5).broadcast(Vec2(80, 300));
+    let item = random_matrix(Vec2(1, 1), 6).broadcast(Vec2(300, 90));
+    assert_eq!(&row * &item, naive_product(&row, &item));
+    assert_eq!(&row * &b, naive_product(&row, &b));
+}
+
+#[test]
+fn empty_shared_dimension_gives_zeros() {
+    let a: Matrix<i64> = Matrix::new(Vec2(3, 0), vec![]);
+    let b: Matrix<i64> = Matrix::new(Vec2(0, 4), vec![]);
+    assert_eq!(&a * &b, Matrix::new(Vec2(3, 4), vec![0; 12]));
+    assert_eq!(a.try_mul(&b), Ok(Matrix::new(Vec2(3, 4), vec![0; 12])));
+}
@@ -0,0 +1,104 @@
+extern crate ktensor as k;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use k::{Arc, Vec2, Tensor, Context, Graph, Node, State, Variable};
+
+static EVALUATIONS: AtomicUsize = AtomicUsize::new(0);
+
+fn counted_add(vec: Vec<Tensor<f64>>) -> Tensor<f64> {
+    EVALUATIONS.fetch_add(1, Ordering::SeqCst);
+    &vec[0] + &vec[1]
+}
+
+fn counted_add_prime(gradient: &Tensor<f64>, _: Vec<&Tensor<f64>>) -> Vec<Tensor<f64>> {
+    vec![gradient.clone(), gradient.clone()]
+}
+
+#[test]
+fn shared_subgraphs_run_once() {
+    let input_x = Arc::new(Variable::new("input_x".to_string(), Vec2(1, 1)));
+    let mut variable_context = Context::new();
+    variable_context.set(input_x.get_id(), Tensor::from_vec(Vec2(1, 1), vec![1.0]));
+
+    // every layer consumes the previous one twice, so evaluating parameters separately takes 2^depth calls
+    let depth = 40;
+    let mut graph_head: Arc<dyn Graph<f64>> = input_x.clone();
+    for i in 0..depth {
+        graph_head = Arc::new(Node::with_dim(format!("double_{}", i), counted_add, counted_add_prime, vec![graph_head.clone(), graph_head.clone()], Vec2(1, 1)));
+    }
+
+    let result = k::execute(graph_head.clone(), &Context::new(), &variable_context);
+    assert_eq!(result.get(Vec2(0, 0)), 2f64.powi(depth as i32));
+    assert_eq!(EVALUATIONS.load(Ordering::SeqCst), depth);
+
+    let mut history = Context::new();
+    graph_head.train(&Context::new(), &variable_context, &mut history);
+    assert_eq!(EVALUATIONS.load(Ordering::SeqCst), 2 * depth);
+    assert!(history.get("double_0".to_string()).is_some());
+}
+
+#[test]
+fn shared_gradients_are_summed() {
+    let weight = Arc::new(State::with_initializer("weight".to_string(), Vec2(1, 1), k::init::Constant(2.0)));
+    let input_x = Arc::new(Variable::new("input_x".to_string(), Vec2(1, 1)));
+    let input_y = Arc::new(Variable::new("input_y".to_string(), Vec2(1, 1)));
+    let mut variable_context = Context::new();
+    variable_context.set(input_x.get_id(), Tensor::from_vec(Vec2(1, 1), vec![3.0]));
+    variable_context.set(input_y.get_id(), Tensor::from_vec(Vec2(1, 1), vec![5.0]));
+
+    // the weight is tied across both towers, so dC/dweight = x + y
+    let tower_x = Arc::new(k::op::mul("tower_x".to_string(), weight.clone(), input_x.clone()));
+    let tower_y = Arc::new(k::op::mul("tower_y".to_string(), weight.clone(), input_y.clone()));
+    let towers = Arc::new(k::op::add("towers".to_string(), tower_x.clone(), tower_y));
+
+    // the tower is consumed twice, so dC/dweight = 2x
+    let fan_out = Arc::new(k::op::add("fan_out".to_string(), tower_x.clone(), tower_x));
+
+    for (cost, expected) in [(towers as Arc<dyn Graph<f64>>, 8.0), (fan_out, 6.0)] {
+        let mut state_context = Context::new();
+        weight.initialize(&mut state_context);
+        let gradients = k::gradients(cost.clone(), &state_context, &variable_context);
+        assert_eq!(gradients.get(weight.get_id()).unwrap().get(Vec2(0, 0)), expected);
+
+        k::train(cost, &mut state_context, &variable_context, &mut Context::new(), -0.1);
+        assert!((state_context.get(weight.get_id()).unwrap().get(Vec2(0, 0)) - (2.0 - 0.1 * expected)).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn reused_history_is_not_stale() {
+    let weight = Arc::new(State::with_initializer("weight".to_string(), Vec2(1, 1), k::init::Constant(2.0)));
+    let input_x = Arc::new(Variable::new("input_x".to_string(), Vec2(1, 1)));
+    let cost: Arc<dyn Graph<f64>> = Arc::new(k::op::mul("cost".to_string(), weight.clone(), input_x.clone()));
+
+    let mut state_context = Context::new();
+    weight.initialize(&mut state_context);
+    let mut variable_context = Context::new();
+    variable_context.set(input_x.get_id(), Tensor::from_vec(Vec2(1, 1), vec![3.0]));
+
+    let mut history = Context::new();
+    assert_eq!(cost.train(&state_context, &variable_context, &mut history).get(Vec2(0, 0)), 6.0);
+    variable_context.set(input_x.get_id(), Tensor::from_vec(Vec2(1, 1), vec![5.0]));
+    assert_eq!(cost.train(&state_context, &variable_context, &mut history).get(Vec2(0, 0)), 10.0);
+
+    // the gradient dC/dweight = x must come from the batch of this step, not of an earlier pass in `history`
+    k::train(cost.clone(), &mut state_context, &variable_context, &mut history, -0.1);
+    assert!((state_context.get(weight.get_id()).unwrap().get(Vec2(0, 0)) - 1.5).abs() < 1e-12);
+    assert_eq!(history.get(input_x.get_id()).unwrap().get(Vec2(0, 0)), 5.0);
+}
+
+#[test]
+fn train_step_is_linear_in_rate() {
+    let weight = Arc::new(State::with_initializer("weight".to_string(), Vec2(1, 1), k::init::Constant(2.0)));
+    let input_x = Arc::new(Variable::new("input_x".to_string(), Vec2(1, 1)));
+    let mut variable_context = Context::new();
+    variable_context.set(input_x.get_id(), Tensor::from_vec(Vec2(1, 1), vec![3.0]));
+
+    // mul passes the incoming gradient on, so a cost seeded with `rate` instead of 1 would move by rate^2 * x
+    let cost: Arc<dyn Graph<f64>> = Arc::new(k::op::mul("cost".to_string(), weight.clone(), input_x.clone()));
+    for &rate in &[-0.1, -0.5, -2.0] {
+        let mut state_context = Context::new();
+        weight.initialize(&mut state_context);
+        k::train(cost.clone(), &mut state_context, &variable_context, &mut Context::new(), rate);
+        assert!((state_context.get(weight.get_id()).unwrap().get(Vec2(0, 0)) - (2.0 + rate * 3.0)).abs() < 1e-12);
+    }
+}
@@ -3,46 +3,27 @@ use k::{Arc, Vec2, Tensor, Context, Graph, State, Variable};
 use std::path::{Path};
 use std::fs::{File};
 use std::io::{BufReader, Read};
-use std::error::{Error};
 use std::cmp;
 
-fn read_u8(reader: &mut Read) -> u8 {
-    use std::mem;
-
-    let mut buf: [u8; 1] = [0];
-    match reader.read_exact(&mut buf).map(|_| {
-        let data: u8 = unsafe { mem::transmute(buf) };
-        data
-    }) {
-        Err(reason) => panic!("failed to read u8: {}", Error::description(&reason)),
-        Ok(byte)    => byte,
-    }
-}
-
-fn read_u32(reader: &mut Read) -> u32 {
-    use std::mem;
-
+fn read_u32(reader: &mut dyn Read) -> u32 {
     let mut buf: [u8; 4] = [0, 0, 0, 0];
-    match reader.read_exact(&mut buf).map(|_| {
-        let data: u32 = unsafe { mem::transmute(buf) };
-        data
-    }) {
-        Err(reason) => panic!("failed to read u32: {}", Error::description(&reason)),
+    match reader.read_exact(&mut buf).map(|_| u32::from_ne_bytes(buf)) {
+        Err(reason) => panic!("failed to read u32: {}", reason),
         Ok(byte)    => byte,
     }
 }
 
 fn read_mnist(labels_path: &Path, labels_checknum: u32, data_path: &Path, data_checknum: u32, batch_size: Option<usize>, sample_size: Option<usize>) -> Vec<(Tensor<f32>, Tensor<f32>)> {
-    let labels_file = match File::open(&labels_path) {
-        Err(reason) => panic!("failed to open {}: {}", labels_path.display(), Error::description(&reason)),
+    let labels_file = match File::open(labels_path) {
+        Err(reason) => panic!("failed to open {}: {}", labels_path.display(), reason),
         Ok(file)    => file,
     };
-    let data_file = match File::open(&data_path) {
-        Err(reason) => panic!("failed to open {}: {}", data_path.display(), Error::description(&reason)),
+    let data_file = match File::open(data_path) {
+        Err(reason) => panic!("failed to open {}: {}", data_path.display(), reason),
         Ok(file)    => file,
     };
-    let ref mut labels_reader = BufReader::new(labels_file);
-    let ref mut data_reader = BufReader::new(data_file);
+    let labels_reader = &mut BufReader::new(labels_file);
+    let data_reader = &mut BufReader::new(data_file);
 
     let labels_magic = u32::from_be(read_u32(labels_reader));
     let data_magic = u32::from_be(read_u32(data_reader));
@@ -63,14 +44,12 @@ fn read_mnist(labels_path: &Path, labels_checknum: u32, data_path: &Path, data_c
     let mut sample_vec: Vec<(Tensor<f32>, Tensor<f32>)> = Vec::with_capacity(sample_count/batch_count);
     for _ in 0..sample_count/batch_count {
         let mut sample_data = vec![0u8; batch_count*rows*columns];
-        match data_reader.read_exact(sample_data.as_mut()) {
-            Err(reason) => panic!("failed to read data byte array: {}", Error::description(&reason)),
-            Ok(_)       => (),
+        if let Err(reason) = data_reader.read_exact(sample_data.as_mut()) {
+            panic!("failed to read data byte array: {}", reason);
         }
         let mut sample_labels_byte = vec![0u8; batch_count];
-        match labels_reader.read_exact(sample_labels_byte.as_mut()) {
-            Err(reason) => panic!("failed to read labels byte array: {}", Error::description(&reason)),
-            Ok(_)       => (),
+        if let Err(reason) = labels_reader.read_exact(sample_labels_byte.as_mut()) {
+            panic!("failed to read labels byte array: {}", reason);
         }
 
         let sample_data = sample_data.iter().map(|&x| (x as f32 - 128.0) / 74.0).collect();
@@ -109,7 +88,7 @@ fn mnist(){
 
     // Initialize
     let mut variable_context = Context::<f32>::with_capacity(2);
-    Variable::init_f32(vec![input_x.clone(), target_y.clone()], &mut variable_context);
+    Variable::init(vec![input_x.clone(), target_y.clone()], &mut variable_context);
 
 
     ///////////
@@ -118,7 +97,7 @@ fn mnist(){
 
     let layers: usize = 2;
     let mut states = Vec::<Arc<State>>::with_capacity(2 * layers);
-    let mut graph_head: Arc<Graph<f32>> = input_x.clone();
+    let mut graph_head: Arc<dyn Graph<f32>> = input_x.clone();
 
     {
         let w = Arc::new(State::new(format!("weight_w_{}", 1), Vec2(28 * 28, 64)));
@@ -127,7 +106,7 @@ fn mnist(){
         let dot = Arc::new(k::op::dot::<f32>(format!("layer_{}_dot", 1), graph_head.clone(), w.clone()));
         let add = Arc::new(k::op::add::<f32>(format!("layer_{}_add", 1), dot, b.clone()));
 
-        let relu = Arc::new(k::op::relu_f32(format!("layer_{}_relu", 1), add));
+        let relu = Arc::new(k::op::relu(format!("layer_{}_relu", 1), add));
 
         graph_head = relu;
 
@@ -142,7 +121,7 @@ fn mnist(){
         let dot = Arc::new(k::op::dot::<f32>(format!("layer_{}_dot", 2), graph_head.clone(), w.clone()));
         let add = Arc::new(k::op::add::<f32>(format!("layer_{}_add", 2), dot, b.clone()));
 
-        let relu = Arc::new(k::op::relu_f32(format!("layer_{}_relu", 2), add));
+        let relu = Arc::new(k::op::relu(format!("layer_{}_relu", 2), add));
 
         graph_head = relu;
 
@@ -150,12 +129,12 @@ fn mnist(){
         states.push(b);
     }
 
-    let softmax = Arc::new(k::op::softmax_f32(format!("layer_{}_softmax", 3), graph_head.clone()));
-    let xentropy = Arc::new(k::cost::softmax_cross_entropy_f32(format!("layer_{}_xentropy", 3), softmax.clone(), target_y.clone()));
+    let softmax = Arc::new(k::op::softmax(format!("layer_{}_softmax", 3), graph_head.clone()));
+    let xentropy = Arc::new(k::cost::softmax_cross_entropy(format!("layer_{}_xentropy", 3), softmax.clone(), target_y.clone()));
 
     // initialize states
     let mut state_context = Context::<f32>::with_capacity(2 * layers);
-    State::init_f32(states, &mut state_context);
+    State::init(states, &mut state_context);
 
 
     //////////////
@@ -171,7 +150,7 @@ fn mnist(){
 
         let train_labels_path = Path::new("data/train-labels-idx1-ubyte");
         let train_data_path = Path::new("data/train-images-idx3-ubyte");
-        let training_vec = read_mnist(&train_labels_path, 2049, &train_data_path, 2051, Some(batch_size), Some(sample_size));
+        let training_vec = read_mnist(train_labels_path, 2049, train_data_path, 2051, Some(batch_size), Some(sample_size));
 
         let mut history = Context::<f32>::with_capacity(5 * layers + 4);
 
@@ -204,16 +183,16 @@ fn mnist(){
 
         let test_labels_path = Path::new("data/t10k-labels-idx1-ubyte");
         let test_data_path = Path::new("data/t10k-images-idx3-ubyte");
-        let test_vec = read_mnist(&test_labels_path, 2049, &test_data_path, 2051, batch_size, sample_size);
+        let test_vec = read_mnist(test_labels_path, 2049, test_data_path, 2051, batch_size, sample_size);
 
         let (ref a, ref b) = test_vec[0];
         variable_context.set(input_x.get_id(), a.clone());
         variable_context.set(target_y.get_id(), b.clone());
 
-        let result_tensor = k::op::softmax_round_f32(k::execute(softmax.clone(), &state_context, &variable_context)) + (k::op::softmax_round_f32(b.clone()) * -1);
-        let total = result_tensor.buffer().len() as f64;
+        let result_tensor = k::op::softmax_round(k::execute(softmax.clone(), &state_context, &variable_context)) - k::op::softmax_round(b.clone());
+        let total = result_tensor.iter().len() as f64;
         let mut score = 0.0;
-        for &i in result_tensor.buffer().iter() {
+        for i in result_tensor.iter() {
             if i == 0 {
                 score += 1.0;
             }
@@ -0,0 +1,49 @@
+extern crate ktensor as k;
+use k::{Arc, Vec2, Tensor, Context, Graph, State, Variable, Optimizer};
+
+/// Takes `steps` steps of `optimizer` on (w - 3)^2 from w = 0 and returns w
+fn minimize(optimizer: &mut dyn Optimizer<f64>, steps: usize) -> f64 {
+    let weight = Arc::new(State::with_initializer("weight".to_string(), Vec2(1, 1), k::init::Zeros));
+    let target = Arc::new(Variable::new("target".to_string(), Vec2(1, 1)));
+    let error = Arc::new(k::op::sub("error".to_string(), weight.clone(), target.clone()));
+    let cost: Arc<dyn Graph<f64>> = Arc::new(k::op::mul("cost".to_string(), error.clone(), error));
+
+    let mut state_context = Context::new();
+    weight.initialize(&mut state_context);
+    let mut variable_context = Context::new();
+    variable_context.set(target.get_id(), Tensor::from_vec(Vec2(1, 1), vec![3.0]));
+    let mut history = Context::new();
+    for _ in 0..steps {
+        k::train_with(cost.clone(), &mut state_context, &variable_context, &mut history, optimizer);
+    }
+    state_context.get(weight.get_id()).unwrap().get(Vec2(0, 0))
+}
+
+#[test]
+fn momentum_converges() {
+    let mut momentum = k::optimizer::Momentum::new(-0.05, 0.9);
+    assert!((minimize(&mut momentum, 200) - 3.0).abs() < 1e-3);
+
+    let mut nesterov = k::optimizer::Momentum::new(-0.05, 0.9);
+    nesterov.nesterov = true;
+    assert!((minimize(&mut nesterov, 200) - 3.0).abs() < 1e-3);
+
+    let mut dampened = k::optimizer::Momentum::new(-0.05, 0.9);
+    dampened.dampening = 0.5;
+    assert!((minimize(&mut dampened, 200) - 3.0).abs() < 1e-3);
+}
+
+#[test]
+fn adam_converges() {
+    let mut adam = k::optimizer::Adam::new(-0.1);
+    assert!((minimize(&mut adam, 500) - 3.0).abs() < 1e-2);
+
+    let mut amsgrad = k::optimizer::Adam::new(-0.1);
+    amsgrad.amsgrad = true;
+    assert!((minimize(&mut amsgrad, 500) - 3.0).abs() < 1e-2);
+
+    // decoupled decay settles where the gradient 2(w - 3) balances the decay of w, below the minimum
+    let mut adamw = k::optimizer::AdamW::new(-0.1, 0.1);
+    let w = minimize(&mut adamw, 500);
+    assert!(w < 3.0 && w > 2.5);
+}
@@ -0,0 +1,18 @@
+extern crate ktensor as k;
+use std::panic;
+use k::math::{Matrix, Vec2};
+
+#[test]
+fn pooled_workers_are_reused_and_survive_panics() {
+    k::math::set_threads(4);
+    let matrix = Matrix::new(Vec2(512, 512), (0..512 * 512).map(|i| i as f64).collect());
+    let expected: Vec<f64> = (0..512 * 512).map(|i| i as f64 * 2.0).collect();
+    for _ in 0..50 {
+        assert_eq!((&matrix + &matrix).to_flattened(), expected);
+    }
+
+    // a panic on a worker reaches the caller once every range has finished
+    let result = panic::catch_unwind(|| matrix.map(|x| if x == 200000.0 { panic!("bad item") } else { x }));
+    assert!(result.is_err());
+    assert_eq!((&matrix + &matrix).to_flattened(), expected);
+}
@@ -0,0 +1,42 @@
+extern crate ktensor as k;
+use k::{Arc, Vec2, Context, State};
+use std::sync::{Mutex};
+use std::thread;
+
+/// tests in this file share the generator of `ktensor`, so they must not draw concurrently
+static GENERATOR: Mutex<()> = Mutex::new(());
+
+/// Seeds the generator and returns the starting values of every `State`
+fn initial_states(seed: u64, states: &[Arc<State>]) -> Vec<Vec<f64>> {
+    k::random::set_seed(seed);
+    let mut context = Context::new();
+    State::init(states.to_vec(), &mut context);
+    states.iter().map(|state| context.get(state.get_id()).unwrap().clone().to_flattened()).collect()
+}
+
+#[test]
+fn same_seed_gives_same_initial_states() {
+    let _lock = GENERATOR.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    let states = vec![
+        Arc::new(State::new("weight_w_1".to_string(), Vec2(2, 4))),
+        Arc::new(State::with_initializer("weight_b_1".to_string(), Vec2(1, 4), k::init::Uniform { low: -0.1, high: 0.1 })),
+        Arc::new(State::with_initializer("weight_w_2".to_string(), Vec2(4, 2), k::init::Orthogonal { gain: 1.0 })),
+    ];
+
+    let first = initial_states(7, &states);
+    let second = initial_states(7, &states);
+    assert_eq!(first, second);
+    assert!(first[0].iter().any(|&x| x != 0.0));
+    assert_ne!(first, initial_states(8, &states));
+}
+
+#[test]
+fn seed_applies_to_every_thread() {
+    let _lock = GENERATOR.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    k::random::set_seed(3);
+    let expected = k::random::normal(16, 0.0, 1.0);
+
+    k::random::set_seed(3);
+    let drawn = thread::spawn(|| k::random::normal(16, 0.0, 1.0)).join().unwrap();
+    assert_eq!(drawn, expected);
+}